@@ -0,0 +1,18 @@
+#![feature(test)]
+extern crate test;
+
+use aoc2018::days::day12::{part1, part2, Day12};
+use aoc2018::solution::Solution;
+use test::Bencher;
+
+#[bench]
+fn bench_part1(bench: &mut Bencher) {
+    let input = Day12.input();
+    bench.iter(|| part1(input).unwrap());
+}
+
+#[bench]
+fn bench_part2(bench: &mut Bencher) {
+    let input = Day12.input();
+    bench.iter(|| part2(input).unwrap());
+}
@@ -0,0 +1,367 @@
+//! Watches the day 13 carts in a Direct2D window. This is its own package so
+//! the Windows-only dependencies don't get in the way of building the rest.
+
+use aoc2018::days::day13::{parse_grid, tick, Cart, Day13, Mine};
+use aoc2018::geom::{Direction::*, Point2i};
+use aoc2018::solution;
+use aoc2018::viz::{self, joins, Renderer};
+use aoc2018::viz::{BGCOLOR, CART_COLOR, DEFAULT_STEP, TRACK_COLOR};
+use math2d::*;
+
+use direct2d::{
+    brush::SolidColorBrush,
+    device_context::DeviceContext,
+    enums::BitmapOptions,
+    geometry::PathGeometry,
+    image::{Bitmap, Bitmap1},
+    stroke_style::StrokeStyle,
+    RenderTarget,
+};
+
+struct D2dRenderer<'a> {
+    rt: &'a mut RenderTarget,
+    track_brush: SolidColorBrush,
+    cart_brush: SolidColorBrush,
+    stroke: StrokeStyle,
+    arc: PathGeometry,
+}
+
+impl<'a> D2dRenderer<'a> {
+    fn new(rt: &'a mut RenderTarget) -> D2dRenderer<'a> {
+        use direct2d::enums::CapStyle;
+        use direct2d::enums::{FigureBegin::Hollow, FigureEnd::Open};
+        use math2d::{ArcSegment, ArcSize, SweepDirection};
+
+        let track_brush = SolidColorBrush::new(rt, TRACK_COLOR).unwrap();
+        let cart_brush = SolidColorBrush::new(rt, CART_COLOR).unwrap();
+        let stroke = StrokeStyle::create(&rt.factory())
+            .with_start_cap(CapStyle::Round)
+            .with_end_cap(CapStyle::Round)
+            .build()
+            .unwrap();
+
+        let arc = PathGeometry::create(&rt.factory())
+            .unwrap()
+            .with_figure((0.0, 0.5), Hollow, Open, |figure| {
+                figure.add_arc(&ArcSegment {
+                    point: (0.5, 0.0).into(),
+                    size: (0.3, 0.3).into(),
+                    rotation_angle: 0.0,
+                    sweep_direction: SweepDirection::CounterClockwise,
+                    arc_size: ArcSize::Large,
+                })
+            })
+            .finish()
+            .unwrap();
+
+        D2dRenderer {
+            rt,
+            track_brush,
+            cart_brush,
+            stroke,
+            arc,
+        }
+    }
+
+    /// Carts are drawn straight onto the window, so they need the same
+    /// centering `draw_tex` applies to the map.
+    fn offset(&self) -> (f32, f32) {
+        let size = self.rt.size();
+        if size.width > size.height {
+            ((size.width - size.height) / 2.0, 0.0)
+        } else {
+            (0.0, (size.height - size.width) / 2.0)
+        }
+    }
+
+    fn draw_arc(&mut self, scale: [f32; 2], x: f32, y: f32) {
+        let transform = Matrix3x2f::scaling(scale, (0.5, 0.5)) * Matrix3x2f::translation([x, y]);
+        self.rt.set_transform(&transform);
+        self.rt
+            .draw_geometry(&self.arc, &self.track_brush, 0.2, Some(&self.stroke));
+        self.rt.set_transform(&Matrix3x2f::IDENTITY);
+    }
+}
+
+impl<'a> Renderer for D2dRenderer<'a> {
+    fn clear(&mut self) {
+        self.rt.clear(BGCOLOR);
+    }
+
+    fn draw_track(&mut self, track: u8, left: u8, right: u8, x: usize, y: usize) {
+        let brush = &self.track_brush;
+        let stroke = Some(&self.stroke);
+        let x = x as f32 + 0.5;
+        let y = y as f32 + 0.5;
+        match track {
+            b'-' => {
+                self.rt
+                    .draw_line((x + 0.0, y + 0.5), (x + 1.0, y + 0.5), brush, 0.2, stroke);
+            }
+            b'|' => {
+                self.rt
+                    .draw_line((x + 0.5, y + 0.0), (x + 0.5, y + 1.0), brush, 0.2, stroke);
+            }
+            b'/' => {
+                if joins(left) {
+                    self.draw_arc([1.0, 1.0], x, y);
+                }
+                if joins(right) {
+                    self.draw_arc([-1.0, -1.0], x, y);
+                }
+            }
+            b'\\' => {
+                if joins(left) {
+                    self.draw_arc([1.0, -1.0], x, y);
+                }
+                if joins(right) {
+                    self.draw_arc([-1.0, 1.0], x, y);
+                }
+            }
+            b'+' => {
+                self.rt
+                    .draw_line((x + 0.0, y + 0.5), (x + 1.0, y + 0.5), brush, 0.2, stroke);
+                self.rt
+                    .draw_line((x + 0.5, y + 0.0), (x + 0.5, y + 1.0), brush, 0.2, stroke);
+            }
+            _ => {}
+        }
+    }
+
+    fn draw_cart(&mut self, cart: &Cart) {
+        let (ox, oy) = self.offset();
+        let x = ox + cart.pos.x as f32 + 0.5;
+        let y = oy + cart.pos.y as f32 + 0.5;
+
+        let rt = &mut self.rt;
+        let brush = &self.cart_brush;
+        rt.fill_ellipse(((x + 0.5, y + 0.5), 0.6, 0.6), brush);
+        match cart.dir {
+            North => rt.fill_ellipse(((x + 0.5, y + 0.25), 0.3, 0.6), brush),
+            South => rt.fill_ellipse(((x + 0.5, y + 0.75), 0.3, 0.6), brush),
+            East => rt.fill_ellipse(((x + 0.75, y + 0.5), 0.6, 0.3), brush),
+            West => rt.fill_ellipse(((x + 0.25, y + 0.5), 0.6, 0.3), brush),
+        }
+    }
+
+    fn draw_crash(&mut self, crash: Point2i) {
+        let (ox, oy) = self.offset();
+        let x = ox + crash.x as f32 + 0.5;
+        let y = oy + crash.y as f32 + 0.5;
+        let crash = Point2f::new(x, y);
+
+        let brush = &self.cart_brush;
+        let stroke = Some(&self.stroke);
+        self.rt
+            .draw_line(crash + [0.0, 0.0], crash + [1.0, 1.0], brush, 0.2, stroke);
+        self.rt
+            .draw_line(crash + [1.0, 0.0], crash + [0.0, 1.0], brush, 0.2, stroke);
+    }
+}
+
+/// How many cells across the square map texture is, leaving half a cell of
+/// margin around the tracks.
+fn map_size(mine: &Mine) -> f32 {
+    (mine.tracks.width().max(mine.tracks.height()) + 1) as f32
+}
+
+fn draw_tex(tex: &Bitmap, rt: &mut RenderTarget, cells: f32) {
+    use direct2d::enums::BitmapInterpolationMode::Linear;
+    let size = rt.size();
+    let (size, x, y) = if size.width > size.height {
+        (size.height, (size.width - size.height) / 2.0, 0.0)
+    } else {
+        (size.width, 0.0, (size.height - size.width) / 2.0)
+    };
+
+    rt.draw_bitmap(
+        tex,
+        [x, y, x + size, y + size],
+        1.0,
+        Linear,
+        [0.0, 0.0, cells, cells],
+    );
+}
+
+fn fix_dpi(rt: &mut direct2d::RenderTarget, cells: f32) {
+    let psize = rt.pixel_size();
+    let scale = if psize.width > psize.height {
+        psize.height as f32 / cells * 96.0
+    } else {
+        psize.width as f32 / cells * 96.0
+    };
+    rt.set_dpi(scale, scale);
+}
+
+fn draw_map(mine: &Mine, rt: &mut RenderTarget) {
+    rt.begin_draw();
+    viz::draw_map(mine, &mut D2dRenderer::new(rt));
+    rt.end_draw().unwrap();
+}
+
+fn draw_carts(mine: &Mine, rt: &mut RenderTarget) {
+    viz::draw_carts(mine, &mut D2dRenderer::new(rt));
+}
+
+fn viz(input: &str) {
+    use direct2d::factory::Factory1;
+    use direct3d11::enums::{BindFlags, CreateDeviceFlags};
+    use direct3d11::Texture2D;
+    use dxgi::enums::{Format, PresentFlags};
+    use dxgi::swap_chain::SwapChain1;
+    use std::time::{Duration, Instant};
+    use winit::{os::windows::WindowExt, Event, EventsLoop, VirtualKeyCode, Window, WindowEvent};
+
+    let mut mine = parse_grid(input);
+    let cells = map_size(&mine);
+
+    let mut evloop = EventsLoop::new();
+    let window = Window::new(&evloop).unwrap();
+    window.set_title("Day 13");
+
+    let dxgi: dxgi::factory::Factory2 = dxgi::factory::create().unwrap();
+
+    let (_, d3d, _) = direct3d11::Device::create()
+        .with_flags(CreateDeviceFlags::BGRA_SUPPORT | CreateDeviceFlags::DEBUG)
+        .build()
+        .unwrap();
+
+    let mut chain = SwapChain1::create_hwnd(&dxgi, &d3d.as_dxgi())
+        .with_hwnd(window.get_hwnd() as _)
+        .build()
+        .unwrap();
+
+    let d2d = Factory1::new().unwrap();
+    let dev = direct2d::Device::create(&d2d, &d3d.as_dxgi()).unwrap();
+    let mut ctx = DeviceContext::create(&dev).unwrap();
+
+    // Create a texture to render to
+    let tex = direct3d11::texture2d::Texture2D::create(&d3d)
+        .with_size(4096, 4096)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_bind_flags(BindFlags::RENDER_TARGET | BindFlags::SHADER_RESOURCE)
+        .build()
+        .unwrap();
+
+    let map = Bitmap1::create(&ctx)
+        .with_dxgi_surface(&tex.as_dxgi())
+        .with_dpi(96.0 * 4096.0 / cells, 96.0 * 4096.0 / cells)
+        .with_options(BitmapOptions::TARGET)
+        .build()
+        .unwrap();
+
+    let backbuffer: Texture2D = chain.buffer(0).unwrap();
+    let mut target = Some(
+        Bitmap1::create(&ctx)
+            .with_dxgi_surface(&backbuffer.as_dxgi())
+            .with_options(BitmapOptions::TARGET | BitmapOptions::CANNOT_DRAW)
+            .build()
+            .unwrap(),
+    );
+    drop(backbuffer);
+
+    ctx.set_target(&map);
+    fix_dpi(&mut ctx, cells);
+    draw_map(&mine, &mut ctx);
+
+    ctx.set_target(target.as_ref().unwrap());
+    ctx.begin_draw();
+    fix_dpi(&mut ctx, cells);
+    draw_tex(&map, &mut ctx, cells);
+    draw_carts(&mine, &mut ctx);
+    ctx.end_draw().unwrap();
+    chain.present(0, PresentFlags::NONE).unwrap();
+
+    let mut quit = false;
+    let mut next_tick = Instant::now() + Duration::from_millis(1_500);
+    let mut step = DEFAULT_STEP;
+    while !quit {
+        let now = Instant::now();
+
+        evloop.poll_events(|event| match event {
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                ..
+            } => {
+                quit = true;
+            }
+
+            Event::WindowEvent {
+                event: WindowEvent::Resized(size),
+                ..
+            } => {
+                target = None;
+                ctx.set_target(&map);
+
+                let (width, height) = size.to_physical(window.get_hidpi_factor()).into();
+                chain
+                    .resize_buffers()
+                    .dimensions(width, height)
+                    .finish()
+                    .unwrap();
+
+                let backbuffer: Texture2D = chain.buffer(0).unwrap();
+                target = Some(
+                    Bitmap1::create(&ctx)
+                        .with_dxgi_surface(&backbuffer.as_dxgi())
+                        .with_options(BitmapOptions::TARGET | BitmapOptions::CANNOT_DRAW)
+                        .build()
+                        .unwrap(),
+                );
+                drop(backbuffer);
+                ctx.set_target(target.as_ref().unwrap());
+            }
+
+            Event::WindowEvent {
+                event: WindowEvent::KeyboardInput { input: key, .. },
+                ..
+            } => {
+                if key.virtual_keycode == Some(VirtualKeyCode::R) {
+                    mine = parse_grid(input);
+                } else if key.virtual_keycode == Some(VirtualKeyCode::W) {
+                    step = viz::faster(step);
+                } else if key.virtual_keycode == Some(VirtualKeyCode::S) {
+                    step = viz::slower(step);
+                } else if key.virtual_keycode == Some(VirtualKeyCode::Z) {
+                    step = DEFAULT_STEP;
+                }
+                next_tick = now + step;
+
+                ctx.begin_draw();
+                fix_dpi(&mut ctx, cells);
+                draw_tex(&map, &mut ctx, cells);
+                draw_carts(&mine, &mut ctx);
+                chain.present(0, PresentFlags::NONE).unwrap();
+                ctx.end_draw().unwrap();
+                next_tick = now + step;
+            }
+
+            _ => (),
+        });
+
+        if now >= next_tick {
+            if let Err(e) = tick(&mut mine) {
+                eprintln!("error: {}", e);
+                break;
+            }
+
+            fix_dpi(&mut ctx, cells);
+            ctx.begin_draw();
+            ctx.clear(BGCOLOR);
+            draw_tex(&map, &mut ctx, cells);
+            draw_carts(&mine, &mut ctx);
+            ctx.end_draw().unwrap();
+            chain.present(0, PresentFlags::NONE).unwrap();
+
+            next_tick += step;
+        }
+
+        if now > next_tick {
+            std::thread::sleep(std::cmp::min(Duration::from_millis(16), now - next_tick));
+        }
+    }
+}
+
+fn main() {
+    viz(&solution::input_from_args(&Day13));
+}
@@ -0,0 +1,104 @@
+use aoc2018::days;
+use aoc2018::input::Input;
+use aoc2018::solution::{self, Solution};
+
+use std::process::exit;
+use std::time::Duration;
+
+const USAGE: &str = "usage: aoc run <day|all> [--part <1|2>] [--input <path>|-]";
+
+struct Timing {
+    day: u32,
+    part: u32,
+    time: Duration,
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    exit(1);
+}
+
+struct Args {
+    solutions: Vec<&'static dyn Solution>,
+    parts: Vec<u32>,
+    input: Input,
+}
+
+fn parse_args() -> Args {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() < 2 || args[0] != "run" {
+        usage();
+    }
+
+    let solutions = match &args[1][..] {
+        "all" => days::all().to_vec(),
+        day => match day.parse().ok().and_then(days::get) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("no solution for day '{}'", day);
+                exit(1);
+            }
+        },
+    };
+
+    let mut parts = vec![1, 2];
+    let mut input = Input::Embedded;
+
+    let mut flags = args[2..].iter();
+    while let Some(flag) = flags.next() {
+        let value = flags.next().unwrap_or_else(|| usage());
+        match (&flag[..], &value[..]) {
+            ("--part", "1") => parts = vec![1],
+            ("--part", "2") => parts = vec![2],
+            ("--input", path) => input = Input::from_arg(path),
+            _ => usage(),
+        }
+    }
+
+    if solutions.len() > 1 && input != Input::Embedded {
+        eprintln!("--input can only be used when running a single day");
+        exit(1);
+    }
+
+    Args {
+        solutions,
+        parts,
+        input,
+    }
+}
+
+fn print_summary(timings: &[Timing]) {
+    println!();
+    println!("{:>4} {:>4} {:>12}", "Day", "Part", "Time");
+    for t in timings {
+        println!("{:>4} {:>4} {:>12?}", t.day, t.part, t.time);
+    }
+
+    let total: Duration = timings.iter().map(|t| t.time).sum();
+    println!("{:>9} {:>12?}", "Total", total);
+}
+
+fn main() {
+    let args = parse_args();
+
+    let mut timings = Vec::new();
+    let mut failed = false;
+    for solution in args.solutions {
+        let input = solution::load_input(solution, &args.input);
+        for &part in &args.parts {
+            println!("Day {} part {}:", solution.day(), part);
+            let (answer, time) = solution::time_part(solution, part, &input);
+            failed |= !solution::print_answer(&answer);
+            timings.push(Timing {
+                day: solution.day(),
+                part,
+                time,
+            });
+        }
+    }
+
+    print_summary(&timings);
+    if failed {
+        exit(1);
+    }
+}
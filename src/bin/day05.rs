@@ -1,73 +1,52 @@
-static INPUT: &str = include_str!("day05.txt");
-
-#[derive(Clone)]
-struct Polymer {
-    data: Vec<u8>,
-}
-
-impl Polymer {
-    fn new(data: &str) -> Polymer {
-        Polymer {
-            data: Vec::from(data.trim()),
+use aoc2018::days::day05::{react, Day05};
+use aoc2018::solution;
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::process::exit;
+
+const STREAM_USAGE: &str = "usage: day05 --stream <path>|-";
+
+/// Reacts a polymer as it's read, without loading it all first, and prints how
+/// long it ends up.
+fn stream(path: &str) -> io::Result<usize> {
+    let reader: Box<dyn BufRead> = match path {
+        "-" => Box::new(BufReader::new(io::stdin())),
+        path => Box::new(BufReader::new(File::open(path)?)),
+    };
+
+    let mut error = None;
+    let units = reader.bytes().map_while(|b| match b {
+        Ok(b) => Some(b),
+        Err(e) => {
+            error = Some(e);
+            None
         }
-    }
+    });
+    let len = react(units).len();
 
-    fn react(&mut self) {
-        let mut i = 0;
-        while self.data.len() > 0 && i < self.data.len() - 1 {
-            let (left, right) = Unit::get(&self.data, i);
-            if left.cancels(right) {
-                self.data.drain(i..i + 2);
-                if i > 0 {
-                    i -= 1;
-                }
-            } else {
-                i += 1;
-            }
-        }
-    }
-}
-
-#[derive(Copy, Clone)]
-struct Unit {
-    id: u8,
-    polarity: bool,
-}
-
-impl Unit {
-    fn new(data: u8) -> Unit {
-        Unit {
-            id: data.to_ascii_uppercase(),
-            polarity: data.is_ascii_uppercase(),
-        }
-    }
-
-    fn get(data: &[u8], i: usize) -> (Unit, Unit) {
-        let left = Unit::new(data[i]);
-        let right = Unit::new(data[i + 1]);
-        (left, right)
-    }
-
-    fn cancels(self, rhs: Unit) -> bool {
-        self.id == rhs.id && self.polarity != rhs.polarity
+    match error {
+        Some(e) => Err(e),
+        None => Ok(len),
     }
 }
 
 fn main() {
-    println!("Part 1:");
-    let mut polymer = Polymer::new(INPUT);
-    polymer.react();
-    println!("Remaining: {}", polymer.data.len());
-
-    println!("Part 2:");
-    let shortest = (b'A'..=b'Z')
-        .map(|a| {
-            let mut polymer = Polymer::new(INPUT);
-            polymer.data.retain(|&b| Unit::new(b).id != a);
-            polymer.react();
-            polymer.data.len()
-        })
-        .min()
-        .unwrap();
-    println!("Shortest: {}", shortest);
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(|a| &a[..]) {
+        Some("--stream") => match args.get(1) {
+            Some(path) if args.len() == 2 => match stream(path) {
+                Ok(len) => println!("{}", len),
+                Err(e) => {
+                    eprintln!("failed to read {}: {}", path, e);
+                    exit(1);
+                }
+            },
+            _ => {
+                eprintln!("{}", STREAM_USAGE);
+                exit(1);
+            }
+        },
+        _ => solution::run(&Day05),
+    }
 }
@@ -1,149 +1,95 @@
-#![feature(range_contains)]
-
-use aoc2018::{extract_columns, get_columns};
-use std::collections::{HashMap, HashSet};
-
-static INPUT: &str = include_str!("day07.txt");
-
-struct Req {
-    id: char,
-    dep: char,
-}
-
-fn requirements() -> impl Iterator<Item = Req> {
-    INPUT
-        .lines()
-        .map(|line| get_columns(line, |c| !c.is_ascii_uppercase()))
-        .filter_map(extract_columns![(x, x, x)])
-        .filter_map(|(_, dep, id)| Some((dep.chars().next()?, id.chars().next()?)))
-        .map(|(dep, id)| Req { dep, id })
-}
-
-#[derive(Debug)]
-struct Graph {
-    open: HashSet<char>,
-    assigned: HashSet<char>,
-    reqs: HashMap<char, HashSet<char>>,
-}
-
-fn can_exec(id: char, graph: &Graph) -> bool {
-    if !graph.open.contains(&id) {
-        return false;
-    }
-
-    if !graph.reqs.contains_key(&id) {
-        return true;
-    }
-
-    graph.reqs[&id].intersection(&graph.open).count() == 0
-}
-
-fn make_graph() -> Graph {
-    let assigned = HashSet::new();
-    let mut open = HashSet::new();
-    let mut reqs: HashMap<char, HashSet<char>> = HashMap::new();
-
-    for req in requirements() {
-        open.insert(req.id);
-        open.insert(req.dep);
-        reqs.entry(req.id).or_default().insert(req.dep);
-    }
-
-    Graph {
-        open,
-        reqs,
-        assigned,
-    }
-}
-
-fn assign_step(graph: &mut Graph) -> Option<(u32, char)> {
-    for b in b'A'..=b'Z' {
-        let c = b as char;
-        if graph.assigned.contains(&c) {
-            continue;
-        }
-        if can_exec(c, &graph) {
-            graph.assigned.insert(c);
-            //println!("Assigned {}", c);
-            return Some(((c as u32 - 'A' as u32) + 61, c));
-        }
-    }
-    None
-}
-
-const NUM_ELVES: usize = 5;
-
-#[derive(Default)]
-struct ElfPool {
-    available: [u32; NUM_ELVES],
-    task: [char; NUM_ELVES],
-}
-
-fn next_available(pool: &ElfPool) -> Option<usize> {
-    for (elf, &time) in pool.available.iter().enumerate() {
-        if time == 0 {
-            return Some(elf);
+use aoc2018::days::day07::{dep_graph, timeline, Day07, Durations};
+use aoc2018::input::Input;
+use aoc2018::solution;
+
+use std::fs;
+use std::process::exit;
+
+/// Prints the steps as a Graphviz graph instead of solving.
+fn print_dot(args: &[String]) {
+    let input = match Input::from_args(args.iter().cloned()) {
+        Ok(input) => solution::load_input(&Day07, &input),
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("usage: day07 --dot [--input] [<path>|-]");
+            exit(1);
         }
-    }
-    None
-}
-
-fn complete_task(task: char, graph: &mut Graph) {
-    graph.assigned.remove(&task);
-    graph.open.remove(&task);
-}
+    };
 
-fn tick(pool: &mut ElfPool, graph: &mut Graph) {
-    for (elf, time) in pool.available.iter_mut().enumerate() {
-        if *time > 0 {
-            *time -= 1;
-            if *time == 0 {
-                complete_task(pool.task[elf], graph);
-            }
+    match dep_graph(&input) {
+        Ok(graph) => print!("{}", graph.to_dot()),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(1);
         }
     }
 }
 
-fn is_complete(pool: &ElfPool, graph: &Graph) -> bool {
-    pool.available.iter().all(|&t| t == 0) && graph.open.is_empty()
-}
+const SCHEDULE_USAGE: &str =
+    "usage: day07 --schedule <workers> <base seconds>|<durations file> [--table|--svg] \
+     [--input] [<path>|-]";
 
-fn part1() {
-    let mut graph = make_graph();
-    while let Some((_, task)) = assign_step(&mut graph) {
-        complete_task(task, &mut graph);
-        print!("{}", task);
+/// Either the puzzle's durations on top of a base number of seconds, or a file
+/// of "X takes N seconds" lines.
+fn durations(arg: &str) -> aoc2018::Result<Durations> {
+    match arg.parse() {
+        Ok(base) => Ok(Durations::letters(base)),
+        Err(_) => fs::read_to_string(arg)?.parse(),
     }
-    println!();
 }
 
-fn part2() {
-    let mut graph = make_graph();
-    let mut pool = ElfPool::default();
-
-    let mut ticks = 0;
-    loop {
-        while let Some(elf) = next_available(&pool) {
-            if let Some((time, task)) = assign_step(&mut graph) {
-                pool.available[elf] = time;
-                pool.task[elf] = task;
-            } else {
-                break;
+/// Runs part 2 with a different number of elves and step durations, like the
+/// puzzle's example of 2 elves and no base time. Prints the time it takes, or
+/// with `--table` or `--svg` who did what when.
+fn schedule(args: &[String]) {
+    let (workers, durations) = match (args.first().and_then(|n| n.parse().ok()), args.get(1)) {
+        (Some(workers), Some(durations_arg)) => match durations(durations_arg) {
+            Ok(durations) => (workers, durations),
+            Err(e) => {
+                eprintln!("error: can't read durations from {}: {}", durations_arg, e);
+                exit(1);
             }
+        },
+        _ => {
+            eprintln!("{}", SCHEDULE_USAGE);
+            exit(1);
         }
-
-        tick(&mut pool, &mut graph);
-        ticks += 1;
-
-        if is_complete(&pool, &graph) {
-            break;
+    };
+
+    let format = args
+        .get(2)
+        .map(|a| &a[..])
+        .filter(|&a| a == "--table" || a == "--svg");
+    let rest = if format.is_some() {
+        &args[3..]
+    } else {
+        &args[2..]
+    };
+
+    let input = match Input::from_args(rest.iter().cloned()) {
+        Ok(input) => solution::load_input(&Day07, &input),
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("{}", SCHEDULE_USAGE);
+            exit(1);
         }
+    };
+
+    let output = timeline(&input, workers, |step| durations.get(step)).map(|t| match format {
+        Some("--table") => t.table().trim_end().to_string(),
+        Some(_) => t.to_svg().trim_end().to_string(),
+        None => t.total.to_string(),
+    });
+    if !solution::print_answer(&output) {
+        exit(1);
     }
-
-    println!("Ticks: {}", ticks);
 }
 
 fn main() {
-    part1();
-    part2();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(|a| &a[..]) {
+        Some("--dot") => print_dot(&args[1..]),
+        Some("--schedule") => schedule(&args[1..]),
+        _ => solution::run(&Day07),
+    }
 }
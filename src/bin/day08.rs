@@ -1,63 +1,49 @@
-static INPUT: &str = include_str!("day08.txt");
+use aoc2018::days::day08::{parse_tree, Day08};
+use aoc2018::input::Input;
+use aoc2018::solution;
 
-fn input() -> impl Iterator<Item = i32> {
-    INPUT.split_whitespace().filter_map(|s| s.parse().ok())
-}
-
-struct Node {
-    children: Vec<Node>,
-    metadata: Vec<i32>,
-}
-
-fn parse_node(iter: &mut impl Iterator<Item = i32>) -> Option<Node> {
-    let child_count = iter.next()?;
-    let meta_count = iter.next()?;
-
-    let mut children = Vec::with_capacity(child_count as usize);
-    for _ in 0..child_count {
-        children.push(parse_node(iter)?);
-    }
+use std::process::exit;
 
-    let mut metadata = Vec::with_capacity(meta_count as usize);
-    for _ in 0..meta_count {
-        metadata.push(iter.next()?);
-    }
+const EXPORT_USAGE: &str = "usage: day08 --export flat|json|dot [--input] [<path>|-]";
 
-    Some(Node { children, metadata })
-}
-
-impl Node {
-    fn self_sum(&self) -> i32 {
-        self.metadata.iter().cloned().sum()
-    }
-
-    fn child_sum(&self) -> i32 {
-        self.children.iter().map(|c| c.meta_sum()).sum()
-    }
-
-    fn meta_sum(&self) -> i32 {
-        self.self_sum() + self.child_sum()
-    }
-    fn indexed_sum(&self) -> i32 {
-        self.metadata
-            .iter()
-            .filter_map(|&i| if i == 0 { None } else { Some((i - 1) as usize) })
-            .filter_map(move |i| self.children.get(i))
-            .map(|c| c.value())
-            .sum()
-    }
+/// Prints the license tree in another format instead of solving.
+fn export(args: &[String]) {
+    let format = match args.first().map(|a| &a[..]) {
+        Some(format @ "flat") | Some(format @ "json") | Some(format @ "dot") => format,
+        _ => {
+            eprintln!("{}", EXPORT_USAGE);
+            exit(1);
+        }
+    };
+
+    let input = match Input::from_args(args[1..].iter().cloned()) {
+        Ok(input) => solution::load_input(&Day08, &input),
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("{}", EXPORT_USAGE);
+            exit(1);
+        }
+    };
 
-    fn value(&self) -> i32 {
-        if self.children.is_empty() {
-            self.self_sum()
-        } else {
-            self.indexed_sum()
+    let tree = match parse_tree(&input) {
+        Ok(tree) => tree,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(1);
         }
+    };
+
+    match format {
+        "flat" => println!("{}", tree.to_flat()),
+        "json" => println!("{}", tree.to_json()),
+        _ => print!("{}", tree.to_dot()),
     }
 }
 
 fn main() {
-    let tree = parse_node(&mut input()).unwrap();
-    println!("Metadata sum: {}", tree.meta_sum());
-    println!("Tree value: {}", tree.value());
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(|a| &a[..]) {
+        Some("--export") => export(&args[1..]),
+        _ => solution::run(&Day08),
+    }
 }
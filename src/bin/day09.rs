@@ -1,73 +1,47 @@
-use std::collections::VecDeque;
+use aoc2018::days::day09::{trace, Day09, GameRules};
+use aoc2018::input::Input;
+use aoc2018::solution;
 
-const PLAYERS: usize = 447;
+use std::process::exit;
 
-type Marble = usize;
+const TRACE_USAGE: &str = "usage: day09 --trace <turns> [--input] [<path>|-]";
 
-fn marbles(max: usize) -> impl Iterator<Item = Marble> {
-    0..=max
-}
-
-#[derive(Default)]
-struct Circle {
-    marbles: VecDeque<Marble>,
-}
-
-impl Circle {
-    fn rotate(&mut self, amt: isize) {
-        if amt >= 0 {
-            for _ in 0..amt {
-                let temp = self.marbles.pop_back().unwrap();
-                self.marbles.push_front(temp);
-            }
-        } else {
-            for _ in 0..-amt {
-                let temp = self.marbles.pop_front().unwrap();
-                self.marbles.push_back(temp);
-            }
+/// Prints the circle after each of the first few turns instead of solving.
+fn print_trace(args: &[String]) {
+    let turns = match args.first().and_then(|n| n.parse().ok()) {
+        Some(turns) => turns,
+        None => {
+            eprintln!("{}", TRACE_USAGE);
+            exit(1);
         }
-    }
-
-    fn push(&mut self, marble: Marble) {
-        self.marbles.push_back(marble);
-    }
-
-    fn pop(&mut self) -> Marble {
-        self.marbles.pop_back().unwrap()
-    }
-}
-
-fn game(scores: &mut [usize; PLAYERS], max: usize) -> Option<()> {
-    let mut circle = Circle::default();
-    circle.push(0);
-    circle.push(1);
-
-    let mut marbles = marbles(max).skip(2);
+    };
+
+    let input = match Input::from_args(args[1..].iter().cloned()) {
+        Ok(input) => solution::load_input(&Day09, &input),
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("{}", TRACE_USAGE);
+            exit(1);
+        }
+    };
 
-    loop {
-        for elf_score in scores.iter_mut() {
-            let marble = marbles.next()?;
-            if marble % 23 == 0 {
-                circle.rotate(7);
-                *elf_score += marble + circle.pop();
-                circle.rotate(-1);
-            } else {
-                circle.rotate(-1);
-                circle.push(marble);
+    match input.parse::<GameRules>() {
+        Ok(rules) => {
+            for line in trace(&rules, turns) {
+                println!("{}", line);
             }
         }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(1);
+        }
     }
 }
 
-fn highscore(max: usize) -> usize {
-    let mut scores = [0; PLAYERS];
-    game(&mut scores, max);
-    scores.iter().cloned().max().unwrap()
-}
-
 fn main() {
-    const LAST_MARBLE_VALUE: usize = 71510;
-
-    println!("Highscore: {}", highscore(LAST_MARBLE_VALUE));
-    println!("100x Highscore: {}", highscore(LAST_MARBLE_VALUE * 100));
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(|a| &a[..]) {
+        Some("--trace") => print_trace(&args[1..]),
+        _ => solution::run(&Day09),
+    }
 }
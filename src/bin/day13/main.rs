@@ -0,0 +1,64 @@
+use aoc2018::days::day13::{parse_grid, Day13};
+use aoc2018::input::Input;
+use aoc2018::solution;
+use aoc2018::viz::{software, terminal};
+
+use std::path::Path;
+use std::process::exit;
+
+/// Pixels per grid cell in recorded frames.
+const FRAME_SCALE: usize = 4;
+const FRAMES_USAGE: &str = "usage: day13 --frames <dir> <count> [--input] [<path>|-]";
+
+/// Writes `count` frames of the carts to `dir` as PPM images instead of
+/// solving.
+fn record_frames(args: &[String]) {
+    let (dir, count) = match (args.first(), args.get(1).and_then(|n| n.parse().ok())) {
+        (Some(dir), Some(count)) => (dir, count),
+        _ => {
+            eprintln!("{}", FRAMES_USAGE);
+            exit(1);
+        }
+    };
+
+    let input = match Input::from_args(args[2..].iter().cloned()) {
+        Ok(input) => solution::load_input(&Day13, &input),
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("{}", FRAMES_USAGE);
+            exit(1);
+        }
+    };
+
+    let mut grid = parse_grid(&input);
+    if let Err(e) = software::record(&mut grid, count, FRAME_SCALE, Path::new(dir)) {
+        eprintln!("failed to write frames to {}: {}", dir, e);
+        exit(1);
+    }
+}
+
+/// Animates the carts in the terminal instead of solving.
+fn play_in_terminal(args: &[String]) {
+    let input = match Input::from_args(args.iter().cloned()) {
+        Ok(input) => solution::load_input(&Day13, &input),
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("usage: day13 --terminal [--input] [<path>|-]");
+            exit(1);
+        }
+    };
+
+    if let Err(e) = terminal::play(&input) {
+        eprintln!("terminal playback failed: {}", e);
+        exit(1);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(|a| &a[..]) {
+        Some("--frames") => record_frames(&args[1..]),
+        Some("--terminal") => play_in_terminal(&args[1..]),
+        _ => solution::run(&Day13),
+    }
+}
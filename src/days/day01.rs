@@ -0,0 +1,87 @@
+use crate::parse::{self, Line, ParseError};
+use crate::scan;
+use crate::solution::Solution;
+use crate::Result;
+use std::collections::HashSet;
+
+static INPUT: &str = include_str!("day01.txt");
+
+/// Accepts one change per line, with or without the `[a, b, ...]` list
+/// punctuation around them.
+fn changes(input: &str) -> std::result::Result<Vec<i32>, ParseError> {
+    parse::lines(input)
+        .map(|line| Line {
+            text: line.text.trim_matches(|c| "[],".contains(c)),
+            ..line
+        })
+        .filter(|line| !line.text.is_empty())
+        .map(|line| line.parse_with(scan!("{}", change)).map(|(change,)| change))
+        .collect()
+}
+
+pub fn part1(input: &str) -> Result<i32> {
+    Ok(changes(input)?.iter().sum())
+}
+
+pub fn part2(input: &str) -> Result<i32> {
+    let changes = changes(input)?;
+    if changes.is_empty() {
+        return Err("no frequency changes given".into());
+    }
+
+    // Every pass goes through the first pass's frequencies shifted along by
+    // the total, so a repeat has to turn up before the shift is bigger than
+    // the spread of the first pass, or it never will
+    let first_pass: Vec<i32> = changes
+        .iter()
+        .scan(0, |frequency, &change| {
+            *frequency += change;
+            Some(*frequency)
+        })
+        .collect();
+    let total = first_pass[first_pass.len() - 1];
+    let highest = first_pass.iter().cloned().fold(0, i32::max);
+    let lowest = first_pass.iter().cloned().fold(0, i32::min);
+    let passes = if total == 0 {
+        1
+    } else {
+        (highest - lowest) / total.abs() + 2
+    };
+
+    let mut set: HashSet<i32> = HashSet::new();
+    set.insert(0);
+
+    let mut shift = 0;
+    for _ in 0..passes {
+        for &value in changes.iter() {
+            shift += value;
+
+            if set.contains(&shift) {
+                return Ok(shift);
+            }
+
+            set.insert(shift);
+        }
+    }
+    Err("the frequency never repeats".into())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
@@ -0,0 +1,102 @@
+use crate::solution::Solution;
+use crate::Result;
+use std::collections::BTreeMap;
+
+static INPUT: &str = include_str!("day02.txt");
+
+fn box_value(id: &str, repeats: &mut BTreeMap<char, i32>) -> (bool, bool) {
+    repeats.clear();
+
+    let mut twos = 0;
+    let mut threes = 0;
+
+    for c in id.chars() {
+        let entry = repeats.entry(c).or_default();
+
+        match *entry {
+            2 => twos -= 1,
+            3 => threes -= 1,
+            _ => (),
+        }
+
+        *entry += 1;
+
+        match *entry {
+            2 => twos += 1,
+            3 => threes += 1,
+            _ => (),
+        }
+    }
+
+    (twos > 0, threes > 0)
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+    let mut repeats = BTreeMap::new();
+
+    let mut twos = 0u64;
+    let mut threes = 0u64;
+
+    for line in input.lines() {
+        let (has_2, has_3) = box_value(line, &mut repeats);
+        if has_2 {
+            twos += 1;
+        }
+        if has_3 {
+            threes += 1;
+        }
+    }
+
+    Ok(twos * threes)
+}
+
+fn box_diff(box1: &str, box2: &str) -> i32 {
+    let mut diff = 0;
+    for (c1, c2) in box1.chars().zip(box2.chars()) {
+        if c1 != c2 {
+            diff += 1;
+        }
+    }
+    diff
+}
+
+fn box_common(box1: &str, box2: &str) -> String {
+    let mut result = String::with_capacity(box1.len());
+    for (c1, c2) in box1.chars().zip(box2.chars()) {
+        if c1 == c2 {
+            result.push(c1);
+        }
+    }
+    result
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    for box1 in input.lines() {
+        for box2 in input.lines() {
+            if box_diff(box1, box2) == 1 {
+                return Ok(box_common(box1, box2));
+            }
+        }
+    }
+    Err("no boxes differ by exactly one character".into())
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
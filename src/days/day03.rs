@@ -0,0 +1,77 @@
+use crate::geom::{Point2i, Recti};
+use crate::grid::Grid;
+use crate::parse::parse_lines;
+use crate::scan;
+use crate::solution::Solution;
+use crate::Result;
+
+static INPUT: &str = include_str!("day03.txt");
+
+type Id = i32;
+
+fn parse_claims(input: &str) -> Result<Vec<(Id, Recti)>> {
+    let claims = parse_lines(input, |line| {
+        let (id, x, y, w, h): (Id, u32, u32, u32, u32) =
+            scan!("#{} @ {},{}: {}x{}", id, x, y, w, h)(line)?;
+        let (x, y, w, h) = (x as i32, y as i32, w as i32, h as i32);
+        Ok((id, Recti::new(x, y, x + w - 1, y + h - 1)))
+    })?;
+    Ok(claims)
+}
+
+/// How many claims cover each square inch of the fabric.
+fn claim_counts(claims: &[(Id, Recti)]) -> Grid<u32> {
+    let size = claims.iter().fold(Recti::EMPTY, |size, (_, rect)| {
+        size.combined_with(Point2i::new(rect.right, rect.bottom))
+    });
+    let width = (size.right + 1).max(0) as usize;
+    let height = (size.bottom + 1).max(0) as usize;
+
+    let mut counts = Grid::new(width, height, 0);
+    for (_, rect) in claims.iter() {
+        for p in rect.points() {
+            counts[p] += 1;
+        }
+    }
+
+    counts
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let claims = parse_claims(input)?;
+    let counts = claim_counts(&claims);
+
+    Ok(counts.iter().filter(|&(_, &n)| n > 1).count())
+}
+
+pub fn part2(input: &str) -> Result<Id> {
+    let claims = parse_claims(input)?;
+    let counts = claim_counts(&claims);
+
+    for (id, rect) in claims.iter() {
+        if rect.points().all(|p| counts[p] == 1) {
+            return Ok(*id);
+        }
+    }
+    Err("every claim overlaps another".into())
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
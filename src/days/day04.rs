@@ -0,0 +1,220 @@
+use crate::parse::{parse_lines, LineError};
+use crate::scan;
+use crate::solution::Solution;
+use crate::Result;
+
+use std::collections::HashMap;
+
+static INPUT: &str = include_str!("day04.txt");
+
+type GuardId = i32;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Ord, Eq, Hash)]
+struct Date {
+    // year: 1518
+    month: i32,
+    day: i32,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Time {
+    hour: i32,
+    minute: i32,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Timestamp {
+    date: Date,
+    time: Time,
+}
+
+#[derive(Copy, Clone)]
+enum Action {
+    Shift(GuardId),
+    Sleep,
+    Wake,
+}
+
+#[derive(Copy, Clone)]
+struct Event {
+    timestamp: Timestamp,
+    action: Action,
+}
+
+fn parse_event(line: &str) -> std::result::Result<Event, LineError> {
+    let (_year, month, day, hour, minute, action): (i32, _, _, _, _, String) = scan!(
+        "[{}-{}-{} {}:{}] {}",
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        action
+    )(line)?;
+
+    let action = match &action[..] {
+        "falls asleep" => Action::Sleep,
+        "wakes up" => Action::Wake,
+        _ if action.starts_with("Guard") => {
+            let (_, id): (String, _) = scan!("[{}] Guard #{} begins shift", timestamp, id)(line)?;
+            Action::Shift(id)
+        }
+        _ => {
+            let column = line.len() - action.len() + 1;
+            return Err(LineError::new(
+                column,
+                format!("unknown action '{}'", action),
+            ));
+        }
+    };
+
+    if !(0..60).contains(&minute) {
+        // The minute comes straight after the first colon
+        let column = line.find(':').unwrap() + 2;
+        return Err(LineError::new(
+            column,
+            format!("minute {} is outside 0..60", minute),
+        ));
+    }
+
+    let timestamp = Timestamp {
+        date: Date { month, day },
+        time: Time { hour, minute },
+    };
+
+    Ok(Event { timestamp, action })
+}
+
+fn events(input: &str) -> Result<Vec<Event>> {
+    let mut events = parse_lines(input, parse_event)?;
+    events.sort_by_key(|e| e.timestamp);
+    Ok(events)
+}
+
+struct GuardSchedule {
+    time_asleep: i32,
+    asleep_times: [i32; 60],
+}
+
+impl GuardSchedule {
+    fn best_minute(&self) -> i32 {
+        self.asleep_times
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, &t)| t)
+            .unwrap()
+            .0 as i32
+    }
+
+    fn max_minute(&self) -> i32 {
+        self.asleep_times[self.best_minute() as usize]
+    }
+}
+
+impl Default for GuardSchedule {
+    fn default() -> GuardSchedule {
+        GuardSchedule {
+            time_asleep: 0,
+            asleep_times: [0; 60],
+        }
+    }
+}
+
+fn parse_sleep_schedule(events: &[Event]) -> Result<HashMap<GuardId, GuardSchedule>> {
+    let mut sleeps = HashMap::<GuardId, GuardSchedule>::new();
+
+    let mut guard_id = None;
+    let mut last_sleep = None;
+
+    for event in events {
+        let Timestamp { date, time } = event.timestamp;
+        let when = format!(
+            "{:02}-{:02} {:02}:{:02}",
+            date.month, date.day, time.hour, time.minute
+        );
+        match event.action {
+            Action::Shift(guard) => guard_id = Some(guard),
+            Action::Sleep => {
+                // Guards only ever sleep during the midnight hour
+                if time.hour != 0 {
+                    return Err(
+                        format!("someone fell asleep at {}, outside 00:00-00:59", when).into(),
+                    );
+                }
+                last_sleep = Some(event.timestamp);
+            }
+            Action::Wake => {
+                let (guard, start) = match (guard_id, last_sleep.take()) {
+                    (Some(guard), Some(start)) => (guard, start),
+                    _ => {
+                        return Err(
+                            format!("someone woke up at {} without falling asleep", when).into(),
+                        )
+                    }
+                };
+                if time.hour != 0 || date != start.date || time.minute <= start.time.minute {
+                    return Err(format!(
+                        "someone woke up at {}, not later in the midnight hour they fell asleep in",
+                        when
+                    )
+                    .into());
+                }
+
+                let start = start.time.minute;
+                let schedule = sleeps.entry(guard).or_default();
+                schedule.time_asleep += time.minute - start;
+                for m in start..time.minute {
+                    schedule.asleep_times[m as usize] += 1;
+                }
+            }
+        }
+    }
+
+    Ok(sleeps)
+}
+
+pub fn part1(input: &str) -> Result<i32> {
+    let events = events(input)?;
+    let schedule = parse_sleep_schedule(&events)?;
+
+    let most_asleep = schedule
+        .iter()
+        .max_by_key(|(_, sched)| sched.time_asleep)
+        .ok_or("no guard ever fell asleep")?;
+    let best_min = most_asleep.1.best_minute();
+
+    Ok(best_min * most_asleep.0)
+}
+
+pub fn part2(input: &str) -> Result<i32> {
+    let events = events(input)?;
+    let schedule = parse_sleep_schedule(&events)?;
+
+    let max_asleep = schedule
+        .iter()
+        .max_by_key(|(_, sched)| sched.max_minute())
+        .ok_or("no guard ever fell asleep")?;
+    let best_min = max_asleep.1.best_minute();
+
+    Ok(best_min * max_asleep.0)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
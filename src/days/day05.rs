@@ -0,0 +1,205 @@
+use crate::solution::Solution;
+use crate::Result;
+
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+
+static INPUT: &str = include_str!("day05.txt");
+
+/// What happens when two units end up next to each other.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Reaction {
+    Nothing,
+    /// Both units vanish.
+    Vanish,
+    /// Both units are replaced by this one, which can go on to react with the
+    /// unit before it.
+    Become(u8),
+}
+
+/// Which pairs of units react, for any alphabet of bytes. The puzzle's rules
+/// are the default: a letter and the same letter in the other case cancel out,
+/// whichever order they're in.
+#[derive(Clone)]
+pub struct ReactionRules {
+    /// Indexed by `left * 256 + right`.
+    reactions: Vec<Reaction>,
+    /// Units that cancel each other are the same type of unit.
+    types: Vec<u8>,
+    order_sensitive: bool,
+    has_transforms: bool,
+}
+
+impl ReactionRules {
+    /// Rules where nothing reacts yet. Unless `order_sensitive` is set, every
+    /// rule that gets added also applies with the two units swapped round.
+    pub fn new(order_sensitive: bool) -> ReactionRules {
+        ReactionRules {
+            reactions: vec![Reaction::Nothing; 256 * 256],
+            types: (0..=255).collect(),
+            order_sensitive,
+            has_transforms: false,
+        }
+    }
+
+    pub fn is_order_sensitive(&self) -> bool {
+        self.order_sensitive
+    }
+
+    /// Makes `left` followed by `right` vanish, and makes them the same type.
+    pub fn cancel(&mut self, left: u8, right: u8) -> &mut ReactionRules {
+        self.add(left, right, Reaction::Vanish);
+
+        let (keep, merge) = (self.types[left as usize], self.types[right as usize]);
+        for t in self.types.iter_mut() {
+            if *t == merge {
+                *t = keep;
+            }
+        }
+        self
+    }
+
+    /// Makes `left` followed by `right` turn into `into`.
+    pub fn transform(&mut self, left: u8, right: u8, into: u8) -> &mut ReactionRules {
+        self.has_transforms = true;
+        self.add(left, right, Reaction::Become(into))
+    }
+
+    fn add(&mut self, left: u8, right: u8, reaction: Reaction) -> &mut ReactionRules {
+        self.reactions[left as usize * 256 + right as usize] = reaction;
+        if !self.order_sensitive {
+            self.reactions[right as usize * 256 + left as usize] = reaction;
+        }
+        self
+    }
+
+    pub fn reaction(&self, left: u8, right: u8) -> Reaction {
+        self.reactions[left as usize * 256 + right as usize]
+    }
+
+    pub fn unit_type(&self, unit: u8) -> u8 {
+        self.types[unit as usize]
+    }
+
+    /// Whether every unit reacts with at most one other unit (in either
+    /// order), like in the puzzle, and reactions only ever make units vanish.
+    fn has_single_partners(&self) -> bool {
+        !self.has_transforms
+            && (0..=255u8).all(|unit| {
+                let mut partners = (0..=255u8).filter(|&other| {
+                    self.reaction(unit, other) != Reaction::Nothing
+                        || self.reaction(other, unit) != Reaction::Nothing
+                });
+                partners.nth(1).is_none()
+            })
+    }
+}
+
+impl Default for ReactionRules {
+    fn default() -> ReactionRules {
+        let mut rules = ReactionRules::new(false);
+        for (upper, lower) in (b'A'..=b'Z').zip(b'a'..=b'z') {
+            rules.cancel(upper, lower);
+        }
+        rules
+    }
+}
+
+lazy_static! {
+    static ref PUZZLE_RULES: ReactionRules = ReactionRules::default();
+}
+
+/// Reacts a polymer in a single pass, keeping the units that have survived so
+/// far on a stack. Only the stack is held in memory, so the units can be
+/// streamed in from anywhere. Whitespace is skipped.
+pub fn react_with(units: impl IntoIterator<Item = u8>, rules: &ReactionRules) -> Vec<u8> {
+    let mut stack: Vec<u8> = Vec::new();
+    for mut unit in units {
+        if unit.is_ascii_whitespace() {
+            continue;
+        }
+        loop {
+            let reaction = match stack.last() {
+                Some(&top) => rules.reaction(top, unit),
+                None => Reaction::Nothing,
+            };
+            match reaction {
+                Reaction::Nothing => stack.push(unit),
+                Reaction::Vanish => {
+                    stack.pop();
+                }
+                Reaction::Become(product) => {
+                    stack.pop();
+                    unit = product;
+                    continue;
+                }
+            }
+            break;
+        }
+    }
+    stack
+}
+
+/// Reacts a polymer with the puzzle's rules.
+pub fn react(units: impl IntoIterator<Item = u8>) -> Vec<u8> {
+    react_with(units, &PUZZLE_RULES)
+}
+
+/// The shortest the polymer can get by taking out every unit of one type
+/// before reacting it. Every type is tried in parallel.
+pub fn shortest_without_one_type(polymer: &[u8], rules: &ReactionRules) -> usize {
+    // When each unit only ever vanishes with one partner, which is the same
+    // type as it, taking out a unit type can't stop any other reaction from
+    // happening, so it's safe to start from the reacted polymer. With more
+    // partners, the one a unit reacted with might be the one taken out.
+    let start = if rules.has_single_partners() {
+        react_with(polymer.iter().cloned(), rules)
+    } else {
+        polymer
+            .iter()
+            .cloned()
+            .filter(|u| !u.is_ascii_whitespace())
+            .collect()
+    };
+
+    let mut types: Vec<u8> = start.iter().map(|&u| rules.unit_type(u)).collect();
+    types.sort_unstable();
+    types.dedup();
+
+    types
+        .into_par_iter()
+        .map(|t| {
+            let units = start.iter().cloned().filter(|&u| rules.unit_type(u) != t);
+            react_with(units, rules).len()
+        })
+        .min()
+        .unwrap_or(start.len())
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(react(input.bytes()).len())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(shortest_without_one_type(input.as_bytes(), &PUZZLE_RULES))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u32 {
+        5
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
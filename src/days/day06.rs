@@ -0,0 +1,105 @@
+use crate::geom::{Point2i, Recti};
+use crate::parse::parse_lines;
+use crate::scan;
+use crate::solution::Solution;
+use crate::Result;
+
+use std::collections::{HashMap, HashSet};
+
+static INPUT: &str = include_str!("day06.txt");
+
+fn coords(input: &str) -> Result<Vec<Point2i>> {
+    let coords = parse_lines(input, |line| {
+        let (x, y) = scan!("{}, {}", x, y)(line)?;
+        Ok(Point2i { x, y })
+    })?;
+    Ok(coords)
+}
+
+fn closest_coord(coords: &[Point2i], pos: Point2i) -> Option<Point2i> {
+    let mut closest = Point2i::ORIGIN;
+    let mut closest_dist = i32::MAX;
+    let mut closest_count = 0;
+    for &c in coords {
+        let dist = c.manhattan(pos);
+        if dist < closest_dist {
+            closest = c;
+            closest_dist = dist;
+            closest_count = 1;
+        } else if closest_dist == dist {
+            closest_count += 1;
+        }
+    }
+
+    if closest_count > 1 {
+        None
+    } else {
+        Some(closest)
+    }
+}
+
+fn bounds(coords: &[Point2i]) -> Recti {
+    coords.iter().fold(Recti::EMPTY, |r, &c| r.combined_with(c))
+}
+
+fn total_dist(coords: &[Point2i], pos: Point2i) -> i32 {
+    coords.iter().map(|&c| c.manhattan(pos)).sum()
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let coords = coords(input)?;
+    let bounds = bounds(&coords);
+
+    let mut counts = HashMap::<Point2i, usize>::new();
+    let mut inf_blacklist = HashSet::new();
+
+    for c in bounds.points() {
+        if let Some(id) = closest_coord(&coords, c) {
+            *counts.entry(id).or_default() += 1;
+            if bounds.is_on_edge(c) {
+                inf_blacklist.insert(id);
+            }
+        }
+    }
+
+    for id in inf_blacklist.iter() {
+        counts.remove(id);
+    }
+
+    let biggest = counts.values().max().ok_or("every region is infinite")?;
+    Ok(*biggest)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let coords = coords(input)?;
+    let bounds = bounds(&coords);
+
+    let mut viable = 0;
+    for c in bounds.points() {
+        if total_dist(&coords, c) < 10_000 {
+            viable += 1;
+        }
+    }
+
+    Ok(viable)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
@@ -0,0 +1,405 @@
+use crate::graph::{DepGraph, NodeId};
+use crate::parse::parse_lines;
+use crate::scan;
+use crate::solution::Solution;
+use crate::{Error, Result};
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+static INPUT: &str = include_str!("day07.txt");
+
+/// Reads "Step X must be finished before step Y can begin." lines, where the
+/// steps can be named anything.
+fn requirements(input: &str) -> Result<Vec<(String, String)>> {
+    let reqs = parse_lines(input, |line| {
+        scan!(
+            "Step {} must be finished before step {} can begin.",
+            before,
+            after
+        )(line)
+    })?;
+    Ok(reqs)
+}
+
+/// The steps and their requirements. Step names are interned by the graph, so
+/// everything else refers to steps by `NodeId`.
+pub fn dep_graph(input: &str) -> Result<DepGraph> {
+    let mut graph = DepGraph::new();
+    for (before, after) in requirements(input)? {
+        graph.add_edge(&before, &after);
+    }
+    Ok(graph)
+}
+
+#[derive(Debug)]
+struct Graph {
+    steps: DepGraph,
+    /// Every step, alphabetically.
+    by_name: Vec<NodeId>,
+    open: HashSet<NodeId>,
+    assigned: HashSet<NodeId>,
+}
+
+fn can_exec(id: NodeId, graph: &Graph) -> bool {
+    graph.open.contains(&id)
+        && graph
+            .steps
+            .prerequisites(id)
+            .all(|p| !graph.open.contains(&p))
+}
+
+fn make_graph(input: &str) -> Result<Graph> {
+    let steps = dep_graph(input)?;
+    if let Some(cycle) = steps.find_cycle() {
+        return Err(cycle.into());
+    }
+
+    let mut by_name: Vec<NodeId> = steps.nodes().collect();
+    by_name.sort_by_key(|&id| steps.name(id));
+    let open = steps.nodes().collect();
+
+    Ok(Graph {
+        steps,
+        by_name,
+        open,
+        assigned: HashSet::new(),
+    })
+}
+
+/// How many seconds each step takes, by name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Durations {
+    table: HashMap<String, u32>,
+}
+
+impl Durations {
+    /// The puzzle's step durations: `base` seconds plus 1 for A, 2 for B and
+    /// so on up to Z.
+    pub fn letters(base: u32) -> Durations {
+        let table = (b'A'..=b'Z')
+            .zip(1..)
+            .map(|(c, n)| ((c as char).to_string(), base + n))
+            .collect();
+        Durations { table }
+    }
+
+    pub fn insert(&mut self, step: &str, seconds: u32) {
+        self.table.insert(step.to_string(), seconds);
+    }
+
+    pub fn get(&self, step: &str) -> Option<u32> {
+        self.table.get(step).cloned()
+    }
+}
+
+/// Reads "X takes N seconds" lines.
+impl FromStr for Durations {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Durations> {
+        let table = parse_lines(input, scan!("{} takes {} seconds", step, seconds))?;
+        Ok(Durations {
+            table: table.into_iter().collect(),
+        })
+    }
+}
+
+fn assign_step(graph: &mut Graph) -> Option<NodeId> {
+    for &id in &graph.by_name {
+        if graph.assigned.contains(&id) {
+            continue;
+        }
+        if can_exec(id, graph) {
+            graph.assigned.insert(id);
+            return Some(id);
+        }
+    }
+    None
+}
+
+/// The elves working on steps. An elf is free when it has no time left on its
+/// task.
+struct ElfPool {
+    available: Vec<u32>,
+    task: Vec<NodeId>,
+}
+
+impl ElfPool {
+    fn new(workers: usize) -> ElfPool {
+        ElfPool {
+            available: vec![0; workers],
+            task: vec![NodeId::default(); workers],
+        }
+    }
+}
+
+fn next_available(pool: &ElfPool) -> Option<usize> {
+    for (elf, &time) in pool.available.iter().enumerate() {
+        if time == 0 {
+            return Some(elf);
+        }
+    }
+    None
+}
+
+fn complete_task(task: NodeId, graph: &mut Graph) {
+    graph.assigned.remove(&task);
+    graph.open.remove(&task);
+}
+
+fn tick(pool: &mut ElfPool, graph: &mut Graph) {
+    for (elf, time) in pool.available.iter_mut().enumerate() {
+        if *time > 0 {
+            *time -= 1;
+            if *time == 0 {
+                complete_task(pool.task[elf], graph);
+            }
+        }
+    }
+}
+
+fn is_complete(pool: &ElfPool, graph: &Graph) -> bool {
+    pool.available.iter().all(|&t| t == 0) && graph.open.is_empty()
+}
+
+/// One step being worked on, from the start of second `start` up to the start
+/// of second `end`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Task {
+    pub worker: usize,
+    pub step: String,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Who worked on what and when, and how long it all took.
+#[derive(Clone, Debug)]
+pub struct Timeline {
+    pub workers: usize,
+    pub tasks: Vec<Task>,
+    pub total: u32,
+}
+
+/// Runs `workers` elves through every step, when each step takes
+/// `duration(name)` seconds. It's an error for a step not to have a duration.
+pub fn timeline(
+    input: &str,
+    workers: usize,
+    duration: impl Fn(&str) -> Option<u32>,
+) -> Result<Timeline> {
+    if workers == 0 {
+        return Err("there has to be at least one worker".into());
+    }
+
+    let mut graph = make_graph(input)?;
+    let mut pool = ElfPool::new(workers);
+    let mut tasks = Vec::new();
+
+    let mut ticks = 0;
+    while !is_complete(&pool, &graph) {
+        while let Some(elf) = next_available(&pool) {
+            let task = match assign_step(&mut graph) {
+                Some(task) => task,
+                None => break,
+            };
+            let step = graph.steps.name(task);
+            let time = duration(step).ok_or_else(|| format!("no duration for step '{}'", step))?;
+            tasks.push(Task {
+                worker: elf,
+                step: step.to_string(),
+                start: ticks,
+                end: ticks + time,
+            });
+
+            if time == 0 {
+                // Instant steps are done as soon as they're picked up
+                complete_task(task, &mut graph);
+            } else {
+                pool.available[elf] = time;
+                pool.task[elf] = task;
+            }
+        }
+
+        if is_complete(&pool, &graph) {
+            break;
+        }
+        tick(&mut pool, &mut graph);
+        ticks += 1;
+    }
+
+    Ok(Timeline {
+        workers,
+        tasks,
+        total: ticks,
+    })
+}
+
+/// How many seconds `workers` elves take to finish every step.
+pub fn build_time(
+    input: &str,
+    workers: usize,
+    duration: impl Fn(&str) -> Option<u32>,
+) -> Result<u32> {
+    Ok(timeline(input, workers, duration)?.total)
+}
+
+const COLUMN_GAP: &str = "   ";
+
+impl Timeline {
+    fn working_on(&self, worker: usize, second: u32) -> Option<&Task> {
+        self.tasks
+            .iter()
+            .find(|t| t.worker == worker && t.start <= second && second < t.end)
+    }
+
+    /// The steps finished by the start of `second`, in the order they were.
+    fn done_by(&self, second: u32) -> Vec<&Task> {
+        let mut done: Vec<&Task> = self.tasks.iter().filter(|t| t.end <= second).collect();
+        done.sort_by_key(|t| (t.end, t.worker));
+        done
+    }
+
+    /// A table like the one in the puzzle, with a row for every second up to
+    /// and including the one where everything's done.
+    pub fn table(&self) -> String {
+        let worker_names: Vec<String> = (1..=self.workers)
+            .map(|w| format!("Worker {}", w))
+            .collect();
+        let width = worker_names
+            .iter()
+            .map(|n| n.len())
+            .chain(self.tasks.iter().map(|t| t.step.len()))
+            .max()
+            .unwrap_or(0);
+        let separator = step_separator(self.tasks.iter().map(|t| &t.step[..]));
+
+        let mut table = String::from("Second");
+        for name in &worker_names {
+            table += &format!("{}{:^width$}", COLUMN_GAP, name, width = width);
+        }
+        table += COLUMN_GAP;
+        table += "Done\n";
+
+        for second in 0..=self.total {
+            let mut row = format!("{:<6}", format!("{:>4}", second));
+            for worker in 0..self.workers {
+                let step = self.working_on(worker, second).map_or(".", |t| &t.step[..]);
+                row += &format!("{}{:^width$}", COLUMN_GAP, step, width = width);
+            }
+            row += COLUMN_GAP;
+            let done: Vec<&str> = self.done_by(second).iter().map(|t| &t.step[..]).collect();
+            row += &done.join(separator);
+            table += row.trim_end();
+            table.push('\n');
+        }
+        table
+    }
+
+    /// A Gantt chart of the timeline as an SVG image, with a row for each
+    /// worker.
+    pub fn to_svg(&self) -> String {
+        const ROW_HEIGHT: u32 = 24;
+        const LABEL_WIDTH: u32 = 80;
+
+        // Long timelines get squeezed to fit into a reasonable width
+        let second_width = (800.0 / f64::from(self.total.max(1))).min(20.0);
+        let x = |second: u32| f64::from(LABEL_WIDTH) + f64::from(second) * second_width;
+        let width = x(self.total).ceil() as u32 + 10;
+        let height = ROW_HEIGHT * (self.workers as u32 + 1);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             font-family=\"monospace\" font-size=\"12\">\n",
+            width, height
+        );
+        for worker in 0..self.workers {
+            let y = ROW_HEIGHT * worker as u32;
+            svg += &format!(
+                "  <text x=\"4\" y=\"{}\">Worker {}</text>\n",
+                y + ROW_HEIGHT * 2 / 3,
+                worker + 1
+            );
+        }
+        for task in &self.tasks {
+            let y = ROW_HEIGHT * task.worker as u32;
+            svg += &format!(
+                "  <rect x=\"{:.1}\" y=\"{}\" width=\"{:.1}\" height=\"{}\" \
+                 fill=\"#8fbcdb\" stroke=\"#336\"><title>{} ({}-{})</title></rect>\n",
+                x(task.start),
+                y + 2,
+                x(task.end) - x(task.start),
+                ROW_HEIGHT - 4,
+                escape_xml(&task.step),
+                task.start,
+                task.end
+            );
+            svg += &format!(
+                "  <text x=\"{:.1}\" y=\"{}\">{}</text>\n",
+                x(task.start) + 2.0,
+                y + ROW_HEIGHT * 2 / 3,
+                escape_xml(&task.step)
+            );
+        }
+        svg += &format!(
+            "  <text x=\"{}\" y=\"{}\">{} seconds</text>\n",
+            LABEL_WIDTH,
+            height - ROW_HEIGHT / 3,
+            self.total
+        );
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// What goes between step names when they're written out in order. Single
+/// letters are run together like in the puzzle, but anything longer needs a
+/// space to tell where one ends and the next begins.
+fn step_separator<'a>(mut steps: impl Iterator<Item = &'a str>) -> &'static str {
+    if steps.all(|step| step.len() == 1) {
+        ""
+    } else {
+        " "
+    }
+}
+
+pub fn part1(input: &str) -> Result<String> {
+    let graph = dep_graph(input)?;
+    let order: Vec<&str> = graph
+        .topo_sort()?
+        .into_iter()
+        .map(|id| graph.name(id))
+        .collect();
+    Ok(order.join(step_separator(order.iter().cloned())))
+}
+
+pub fn part2(input: &str) -> Result<u32> {
+    let durations = Durations::letters(60);
+    build_time(input, 5, |step| durations.get(step))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u32 {
+        7
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
@@ -0,0 +1,379 @@
+use crate::solution::Solution;
+use crate::Result;
+
+use std::error::Error;
+use std::fmt;
+use std::iter::Enumerate;
+use std::str::{FromStr, SplitWhitespace};
+
+static INPUT: &str = include_str!("day08.txt");
+
+/// A node of the license tree. Trees can be far too deep to recurse through,
+/// so everything here, down to cloning and comparing them, is done without
+/// recursion.
+pub struct Node {
+    pub children: Vec<Node>,
+    pub metadata: Vec<i32>,
+}
+
+/// Where the license numbers stopped making sense. `token` counts the numbers
+/// from 0.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeError {
+    pub token: usize,
+    pub message: String,
+}
+
+impl fmt::Display for TreeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "token {}: {}", self.token, self.message)
+    }
+}
+
+impl Error for TreeError {}
+
+struct Tokens<'a> {
+    iter: Enumerate<SplitWhitespace<'a>>,
+    count: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(input: &'a str) -> Tokens<'a> {
+        Tokens {
+            iter: input.split_whitespace().enumerate(),
+            count: 0,
+        }
+    }
+
+    fn next<T: FromStr>(&mut self, what: &str) -> std::result::Result<T, TreeError> {
+        match self.iter.next() {
+            Some((token, text)) => {
+                self.count = token + 1;
+                text.parse().map_err(|_| TreeError {
+                    token,
+                    message: format!("expected {}, got '{}'", what, text),
+                })
+            }
+            None => Err(TreeError {
+                token: self.count,
+                message: format!("expected {}, but the input ended", what),
+            }),
+        }
+    }
+}
+
+/// A node whose children are still being read.
+struct Partial {
+    child_count: usize,
+    meta_count: usize,
+    children: Vec<Node>,
+}
+
+/// Parses a whole license, which has to be exactly one tree. The nodes being
+/// read are kept on an explicit stack, so any depth of tree is fine.
+pub fn parse_tree(input: &str) -> std::result::Result<Node, TreeError> {
+    let mut tokens = Tokens::new(input);
+    let mut stack: Vec<Partial> = Vec::new();
+
+    let tree = 'nodes: loop {
+        let child_count: usize = tokens.next("a child count")?;
+        let meta_count: usize = tokens.next("a metadata count")?;
+        stack.push(Partial {
+            child_count,
+            meta_count,
+            children: Vec::with_capacity(child_count.min(1024)),
+        });
+
+        // Finish every node that has all of its children now
+        while let Some(top) = stack.last() {
+            if top.children.len() < top.child_count {
+                continue 'nodes;
+            }
+
+            let partial = stack.pop().unwrap();
+            let mut metadata = Vec::with_capacity(partial.meta_count.min(1024));
+            for _ in 0..partial.meta_count {
+                metadata.push(tokens.next("a metadata entry")?);
+            }
+            let node = Node {
+                children: partial.children,
+                metadata,
+            };
+
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => break 'nodes node,
+            }
+        }
+    };
+
+    if let Some((token, _)) = tokens.iter.next() {
+        return Err(TreeError {
+            token,
+            message: "unexpected numbers after the end of the tree".into(),
+        });
+    }
+    Ok(tree)
+}
+
+/// A step of a depth-first walk over a tree: every node is entered, then its
+/// children are walked, then it's exited.
+#[derive(Copy, Clone, Debug)]
+pub enum Visit<'a> {
+    Enter(&'a Node),
+    Exit(&'a Node),
+}
+
+/// Walks a tree without recursing, see `Node::walk`.
+pub struct Walk<'a> {
+    /// Nodes that have been entered and how many of their children have been
+    /// walked so far.
+    stack: Vec<(&'a Node, usize)>,
+    root: Option<&'a Node>,
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = Visit<'a>;
+
+    fn next(&mut self) -> Option<Visit<'a>> {
+        if let Some(root) = self.root.take() {
+            self.stack.push((root, 0));
+            return Some(Visit::Enter(root));
+        }
+
+        let (node, walked) = self.stack.last_mut()?;
+        let node = *node;
+        match node.children.get(*walked) {
+            Some(child) => {
+                *walked += 1;
+                self.stack.push((child, 0));
+                Some(Visit::Enter(child))
+            }
+            None => {
+                self.stack.pop();
+                Some(Visit::Exit(node))
+            }
+        }
+    }
+}
+
+impl Node {
+    fn self_sum(&self) -> i32 {
+        self.metadata.iter().cloned().sum()
+    }
+
+    /// Every node in the tree, in the order they're written in the input, as
+    /// they're entered and exited.
+    pub fn walk(&self) -> Walk<'_> {
+        Walk {
+            stack: Vec::new(),
+            root: Some(self),
+        }
+    }
+
+    /// Works out something for every node from the bottom up, without
+    /// recursing. `f` gets each node along with what it gave for that node's
+    /// children, in order.
+    pub fn fold<T>(&self, mut f: impl FnMut(&Node, Vec<T>) -> T) -> T {
+        let mut results: Vec<T> = Vec::new();
+        for visit in self.walk() {
+            if let Visit::Exit(node) = visit {
+                let children = results.split_off(results.len() - node.children.len());
+                results.push(f(node, children));
+            }
+        }
+        results.pop().expect("the root is always exited")
+    }
+
+    pub fn meta_sum(&self) -> i32 {
+        self.fold(|node, children| node.self_sum() + children.iter().sum::<i32>())
+    }
+
+    pub fn value(&self) -> i32 {
+        self.fold(|node, children| {
+            if children.is_empty() {
+                node.self_sum()
+            } else {
+                node.metadata
+                    .iter()
+                    .filter_map(|&i| if i <= 0 { None } else { Some((i - 1) as usize) })
+                    .filter_map(|i| children.get(i))
+                    .sum()
+            }
+        })
+    }
+
+    /// How many nodes there are on the way down to the deepest leaf,
+    /// including the root.
+    pub fn depth(&self) -> usize {
+        self.fold(|_, children| 1 + children.into_iter().max().unwrap_or(0))
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        self.fold(|_, children| children.iter().sum::<usize>().max(1))
+    }
+
+    pub fn max_metadata(&self) -> Option<i32> {
+        self.fold(|node, children| {
+            let own = node.metadata.iter().cloned().max();
+            children.into_iter().fold(own, |a, b| a.max(b))
+        })
+    }
+
+    /// The tree as the puzzle's list of numbers.
+    pub fn to_flat(&self) -> String {
+        let mut out = Vec::new();
+        for visit in self.walk() {
+            match visit {
+                Visit::Enter(node) => {
+                    out.push(node.children.len().to_string());
+                    out.push(node.metadata.len().to_string());
+                }
+                Visit::Exit(node) => out.extend(node.metadata.iter().map(|m| m.to_string())),
+            }
+        }
+        out.join(" ")
+    }
+
+    /// The tree as JSON, with a `children` and a `metadata` array in every
+    /// node.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        for visit in self.walk() {
+            match visit {
+                Visit::Enter(_) => {
+                    if json.ends_with('}') {
+                        json.push(',');
+                    }
+                    json.push_str("{\"children\":[");
+                }
+                Visit::Exit(node) => {
+                    let metadata: Vec<String> =
+                        node.metadata.iter().map(|m| m.to_string()).collect();
+                    json.push_str(&format!("],\"metadata\":[{}]}}", metadata.join(",")));
+                }
+            }
+        }
+        json
+    }
+
+    /// The tree in Graphviz's DOT language, with each node labelled with its
+    /// metadata and numbered in the order it appears in the input.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        let mut ids = Vec::new();
+        let mut next_id = 0;
+        for visit in self.walk() {
+            match visit {
+                Visit::Enter(node) => {
+                    let metadata: Vec<String> =
+                        node.metadata.iter().map(|m| m.to_string()).collect();
+                    dot.push_str(&format!(
+                        "    n{} [label=\"{}\"];\n",
+                        next_id,
+                        metadata.join(" ")
+                    ));
+                    if let Some(parent) = ids.last() {
+                        dot.push_str(&format!("    n{} -> n{};\n", parent, next_id));
+                    }
+                    ids.push(next_id);
+                    next_id += 1;
+                }
+                Visit::Exit(_) => {
+                    ids.pop();
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Rebuilds the tree from the bottom up.
+impl Clone for Node {
+    fn clone(&self) -> Node {
+        self.fold(|node, children| Node {
+            children,
+            metadata: node.metadata.clone(),
+        })
+    }
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Node) -> bool {
+        // As long as every pair of nodes has the same number of children the
+        // two walks stay in step, so comparing node by node is enough
+        self.walk().zip(other.walk()).all(|visits| match visits {
+            (Visit::Enter(a), Visit::Enter(b)) => {
+                a.metadata == b.metadata && a.children.len() == b.children.len()
+            }
+            (Visit::Exit(_), Visit::Exit(_)) => true,
+            _ => false,
+        })
+    }
+}
+
+impl Eq for Node {}
+
+/// Writes the same thing a derived `Debug` would (apart from `{:#?}`'s
+/// indentation).
+impl fmt::Debug for Node {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+        for visit in self.walk() {
+            match visit {
+                Visit::Enter(_) => {
+                    if !first {
+                        fmt.write_str(", ")?;
+                    }
+                    fmt.write_str("Node { children: [")?;
+                    first = true;
+                }
+                Visit::Exit(node) => {
+                    write!(fmt, "], metadata: {:?} }}", node.metadata)?;
+                    first = false;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Takes the tree apart a level at a time, since the default drop would
+/// recurse once per level.
+impl Drop for Node {
+    fn drop(&mut self) {
+        let mut nodes = std::mem::take(&mut self.children);
+        while let Some(mut node) = nodes.pop() {
+            nodes.append(&mut node.children);
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Result<i32> {
+    Ok(parse_tree(input)?.meta_sum())
+}
+
+pub fn part2(input: &str) -> Result<i32> {
+    Ok(parse_tree(input)?.value())
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u32 {
+        8
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
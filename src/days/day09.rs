@@ -0,0 +1,230 @@
+use crate::parse::{self, LineError};
+use crate::ring::Ring;
+use crate::scan;
+use crate::solution::Solution;
+use crate::{Error, Result};
+
+use std::str::FromStr;
+
+static INPUT: &str = include_str!("day09.txt");
+
+type Marble = usize;
+
+/// How a game of marbles is played. The puzzle's rules are that every marble
+/// that's a multiple of 23 scores, along with the marble 7 places
+/// anticlockwise of the current one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GameRules {
+    pub players: usize,
+    pub last_marble: Marble,
+    /// Marbles that are a multiple of this score instead of being placed. 0
+    /// means none do.
+    pub scoring_modulus: usize,
+    /// How far anticlockwise the marble that's taken when scoring is.
+    pub removal_offset: usize,
+}
+
+impl GameRules {
+    pub fn new(players: usize, last_marble: Marble) -> GameRules {
+        GameRules {
+            players,
+            last_marble,
+            scoring_modulus: 23,
+            removal_offset: 7,
+        }
+    }
+}
+
+/// Reads "N players; last marble is worth M points".
+impl FromStr for GameRules {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<GameRules> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or("expected a player count and last marble")?;
+        let rules = line.parse_with(|text| {
+            let (players, last) = scan!(
+                "{} players; last marble is worth {} points",
+                players,
+                last_marble
+            )(text)?;
+            if players == 0 {
+                return Err(LineError::new(1, "there must be at least one player"));
+            }
+            if marble_total(last).is_none() {
+                let column = text.find("worth").map_or(1, |i| i + 7);
+                return Err(LineError::new(
+                    column,
+                    "the last marble is too big to keep score",
+                ));
+            }
+            Ok(GameRules::new(players, last))
+        })?;
+        Ok(rules)
+    }
+}
+
+/// The total of every marble up to `last`, which no score can be more than,
+/// or `None` if it doesn't fit in a `usize`.
+fn marble_total(last: Marble) -> Option<usize> {
+    last.checked_add(1)?.checked_mul(last).map(|n| n / 2)
+}
+
+/// What happened on one turn. `player` is an index into the scores.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Turn {
+    pub player: usize,
+    pub marble: Marble,
+    /// The points the player got this turn, if any.
+    pub score: usize,
+}
+
+/// A game in progress, which plays a turn every time it's iterated.
+pub struct Game {
+    rules: GameRules,
+    circle: Ring<Marble>,
+    scores: Vec<usize>,
+    last_played: Marble,
+}
+
+impl Game {
+    pub fn new(rules: GameRules) -> Game {
+        assert!(rules.players > 0, "a game needs at least one player");
+
+        let mut circle = Ring::new();
+        circle.insert_after(0);
+        Game {
+            rules,
+            circle,
+            scores: vec![0; rules.players],
+            last_played: 0,
+        }
+    }
+
+    pub fn scores(&self) -> &[usize] {
+        &self.scores
+    }
+
+    /// The marbles clockwise from marble 0, the way the puzzle draws them,
+    /// and where the current marble is among them.
+    pub fn circle(&self) -> (Vec<Marble>, usize) {
+        let mut marbles: Vec<Marble> = self.circle.iter().cloned().collect();
+        let zero = marbles.iter().position(|&m| m == 0).unwrap_or(0);
+        marbles.rotate_left(zero);
+        let current = (marbles.len() - zero) % marbles.len().max(1);
+        (marbles, current)
+    }
+}
+
+impl Iterator for Game {
+    type Item = Turn;
+
+    fn next(&mut self) -> Option<Turn> {
+        if self.last_played >= self.rules.last_marble {
+            return None;
+        }
+        self.last_played += 1;
+
+        let marble = self.last_played;
+        let player = (marble - 1) % self.rules.players;
+        let mut score = 0;
+        if marble.is_multiple_of(self.rules.scoring_modulus) {
+            self.circle.rotate(-(self.rules.removal_offset as isize));
+            score = marble + self.circle.remove_current().unwrap_or(0);
+            self.scores[player] += score;
+        } else {
+            self.circle.rotate(1);
+            self.circle.insert_after(marble);
+        }
+
+        Some(Turn {
+            player,
+            marble,
+            score,
+        })
+    }
+}
+
+/// Plays a whole game, returning every player's score and every turn.
+pub fn game(rules: &GameRules) -> (Vec<usize>, Vec<Turn>) {
+    let mut game = Game::new(*rules);
+    let turns = game.by_ref().collect();
+    (game.scores, turns)
+}
+
+/// Draws the circle like the puzzle does, as in `[4] 0 (4) 2 1 3`: the player
+/// who just moved (`-` before anyone has), then the marbles from 0 with the
+/// current one in parentheses.
+pub fn trace_line(player: Option<usize>, marbles: &[Marble], current: usize) -> String {
+    let mut line = match player {
+        Some(player) => format!("[{}]", player + 1),
+        None => "[-]".to_string(),
+    };
+    for (i, marble) in marbles.iter().enumerate() {
+        if i == current {
+            line += &format!(" ({})", marble);
+        } else {
+            line += &format!(" {}", marble);
+        }
+    }
+    line
+}
+
+/// The circle before the first move and after each of the first `turns`
+/// moves, one line each.
+pub fn trace(rules: &GameRules, turns: usize) -> Vec<String> {
+    let mut game = Game::new(*rules);
+    let (marbles, current) = game.circle();
+    let mut lines = vec![trace_line(None, &marbles, current)];
+    while lines.len() <= turns {
+        let turn = match game.next() {
+            Some(turn) => turn,
+            None => break,
+        };
+        let (marbles, current) = game.circle();
+        lines.push(trace_line(Some(turn.player), &marbles, current));
+    }
+    lines
+}
+
+fn highscore(rules: GameRules) -> usize {
+    let mut game = Game::new(rules);
+    game.by_ref().for_each(drop);
+    game.scores.into_iter().max().unwrap_or(0)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let rules: GameRules = input.parse()?;
+    Ok(highscore(rules))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut rules: GameRules = input.parse()?;
+    rules.last_marble = rules
+        .last_marble
+        .checked_mul(100)
+        .filter(|&last| marble_total(last).is_some())
+        .ok_or("the last marble is too big to play 100 times as long")?;
+    Ok(highscore(rules))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn day(&self) -> u32 {
+        9
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
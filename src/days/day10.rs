@@ -0,0 +1,179 @@
+use crate::geom::{Point2i, Recti, Vector2i};
+use crate::ocr;
+use crate::parse::parse_lines;
+use crate::scan;
+use crate::solution::Solution;
+use crate::Result;
+
+struct Light {
+    pos: Point2i,
+    vel: Vector2i,
+}
+
+static INPUT: &str = include_str!("day10.txt");
+
+fn lights(input: &str) -> Result<Vec<Light>> {
+    let lights = parse_lines(input, |line| {
+        let (px, py, vx, vy) = scan!("position=<{},{}> velocity=<{},{}>", px, py, vx, vy)(line)?;
+        Ok(Light {
+            pos: (px, py).into(),
+            vel: [vx, vy].into(),
+        })
+    })?;
+    Ok(lights)
+}
+
+struct Sky {
+    lights: Vec<Light>,
+    ticks: usize,
+}
+
+impl Light {
+    /// Where the light will be `dt` seconds from now, in coordinates wide
+    /// enough that it doesn't matter how far off that is.
+    fn after(&self, dt: i64) -> (i64, i64) {
+        (
+            i64::from(self.pos.x) + i64::from(self.vel.x) * dt,
+            i64::from(self.pos.y) + i64::from(self.vel.y) * dt,
+        )
+    }
+}
+
+impl Sky {
+    /// Moves every light straight to where it is `t` seconds after the start.
+    fn at_time(&mut self, t: usize) {
+        let dt = t as i64 - self.ticks as i64;
+        for light in self.lights.iter_mut() {
+            let (x, y) = light.after(dt);
+            light.pos = Point2i::new(x as i32, y as i32);
+        }
+        self.ticks = t;
+    }
+
+    /// The width plus the height of the box around the lights at time `t`.
+    /// Both are the gap between a pair of lights moving in straight lines, so
+    /// this only ever shrinks and then grows, without any bumps on the way.
+    fn extent(&self, t: usize) -> i64 {
+        let dt = t as i64 - self.ticks as i64;
+        let (mut left, mut top) = (i64::MAX, i64::MAX);
+        let (mut right, mut bottom) = (i64::MIN, i64::MIN);
+        for light in self.lights.iter() {
+            let (x, y) = light.after(dt);
+            left = left.min(x);
+            right = right.max(x);
+            top = top.min(y);
+            bottom = bottom.max(y);
+        }
+        (right - left) + (bottom - top)
+    }
+
+    /// The first time the lights are packed closest together. Doubles the
+    /// time until the extent has started growing, then ternary searches back
+    /// for the bottom.
+    fn convergence_time(&self) -> usize {
+        let mut hi = 0;
+        while self.extent(hi + 1) < self.extent(hi) {
+            hi = hi * 2 + 1;
+        }
+
+        let mut lo = 0;
+        while hi - lo > 2 {
+            let third = (hi - lo) / 3;
+            if self.extent(lo + third) <= self.extent(hi - third) {
+                hi -= third;
+            } else {
+                lo += third;
+            }
+        }
+        (lo..=hi).min_by_key(|&t| self.extent(t)).unwrap()
+    }
+
+    fn bounds(&self) -> Recti {
+        self.lights
+            .iter()
+            .fold(Recti::EMPTY, |r, p| r.combined_with(p.pos))
+    }
+
+    fn light_at(&self, x: i32, y: i32) -> bool {
+        for light in self.lights.iter() {
+            if light.pos.x == x && light.pos.y == y {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn converge(&mut self) {
+        let t = self.convergence_time();
+        self.at_time(t);
+    }
+
+    fn render(&self) -> String {
+        let bounds = self.bounds();
+        let border = format!("+{:-<1$}+", "", (bounds.right - bounds.left + 3) as usize);
+
+        let mut out = String::new();
+        out.push_str(&border);
+        out.push('\n');
+        for y in bounds.top..=bounds.bottom {
+            out.push_str("| ");
+            for x in bounds.left..=bounds.right {
+                if self.light_at(x, y) {
+                    out.push('#');
+                } else {
+                    out.push(' ');
+                }
+            }
+            out.push_str(" |\n");
+        }
+        out.push_str(&border);
+        out
+    }
+
+    /// The letters the lights spell out, if they're in the usual font.
+    fn message(&self) -> Option<String> {
+        ocr::read_message(self.lights.iter().map(|l| l.pos))
+    }
+}
+
+fn converged_sky(input: &str) -> Result<Sky> {
+    let lights = lights(input)?;
+    if lights.is_empty() {
+        return Err("no lights in input".into());
+    }
+
+    let mut sky = Sky { lights, ticks: 0 };
+    sky.converge();
+    Ok(sky)
+}
+
+/// The message the lights spell out, or a picture of them if it can't be read
+/// (like the example's smaller letters).
+pub fn part1(input: &str) -> Result<String> {
+    let sky = converged_sky(input)?;
+    Ok(sky.message().unwrap_or_else(|| sky.render()))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(converged_sky(input)?.ticks)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u32 {
+        10
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
@@ -0,0 +1,163 @@
+use crate::geom::Recti;
+use crate::solution::Solution;
+use crate::summed_area::SummedAreaTable;
+use crate::Result;
+
+use rayon::prelude::*;
+use std::ops::RangeInclusive;
+
+pub type GridId = i32;
+
+/// The puzzle's grid is this many cells on each side.
+pub const GRID_SIZE: usize = 300;
+
+/// The biggest grid whose total power always fits in an `i32`, since no cell
+/// has more than 5 either way.
+pub const MAX_GRID_SIZE: usize = 20_000;
+
+/// A fuel cell, in the puzzle's coordinates, which start from 1 in the top
+/// left.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub x: usize,
+    pub y: usize,
+}
+
+pub fn cell(x: usize, y: usize) -> Cell {
+    Cell { x, y }
+}
+
+fn rack_id(cell: Cell) -> usize {
+    cell.x + 10
+}
+
+/// Worked out in `i64`, which is wide enough for any serial number anywhere
+/// in a grid up to `MAX_GRID_SIZE` across.
+pub fn power_level(grid: GridId, cell: Cell) -> i32 {
+    let rid = rack_id(cell) as i64;
+    let mut level = rid * cell.y as i64;
+    level += i64::from(grid);
+    level *= rid;
+    level %= 1000;
+    level /= 100;
+    level as i32 - 5
+}
+
+static INPUT: &str = include_str!("day11.txt");
+
+#[derive(Copy, Clone)]
+struct Best {
+    cell: Cell,
+    size: usize,
+    power: i32,
+}
+
+fn grid_id(input: &str) -> Result<GridId> {
+    let id = input
+        .trim()
+        .parse()
+        .map_err(|_| format!("expected the grid serial number, got '{}'", input.trim()))?;
+    Ok(id)
+}
+
+/// Sums of power levels, where the table's `(0, 0)` is the puzzle's `1,1`.
+fn power_table(serial: GridId, grid_size: usize) -> SummedAreaTable<i32> {
+    SummedAreaTable::from_fn(grid_size, grid_size, |p| {
+        power_level(serial, cell(p.x as usize + 1, p.y as usize + 1))
+    })
+}
+
+fn square_power(table: &SummedAreaTable<i32>, top_left: Cell, size: usize) -> i32 {
+    let (x, y, s) = (top_left.x as i32 - 1, top_left.y as i32 - 1, size as i32);
+    table.rect_sum(Recti::new(x, y, x + s - 1, y + s - 1))
+}
+
+/// Keeps the first of any ties, so results don't depend on how the work was
+/// split up.
+fn better(a: Best, b: Best) -> Best {
+    if b.power > a.power {
+        b
+    } else {
+        a
+    }
+}
+
+fn best_of_size(table: &SummedAreaTable<i32>, size: usize) -> Best {
+    let last = table.width() - size + 1;
+    (1..=last)
+        .flat_map(|x| (1..=last).map(move |y| cell(x, y)))
+        .map(|cell| Best {
+            cell,
+            size,
+            power: square_power(table, cell, size),
+        })
+        .reduce(better)
+        .expect("every size fits at least once")
+}
+
+/// Finds the square with the most power out of every square whose size is in
+/// `sizes`, returning its top left cell, its size and its power. Each size is
+/// searched in parallel. Ties go to the smallest size, then the leftmost
+/// square, then the topmost.
+///
+/// Panics unless `sizes` is non-empty, every size fits in the grid and the
+/// grid is no bigger than `MAX_GRID_SIZE`.
+pub fn best_square(
+    serial: GridId,
+    grid_size: usize,
+    sizes: RangeInclusive<usize>,
+) -> (Cell, usize, i32) {
+    assert!(
+        grid_size <= MAX_GRID_SIZE,
+        "a {}x{} grid is bigger than the {}x{} limit",
+        grid_size,
+        grid_size,
+        MAX_GRID_SIZE,
+        MAX_GRID_SIZE
+    );
+    assert!(
+        !sizes.is_empty() && *sizes.start() >= 1 && *sizes.end() <= grid_size,
+        "squares of size {:?} don't fit in a {}x{} grid",
+        sizes,
+        grid_size,
+        grid_size
+    );
+
+    let table = power_table(serial, grid_size);
+    let best = sizes
+        .into_par_iter()
+        .map(|size| best_of_size(&table, size))
+        .reduce_with(better)
+        .expect("sizes isn't empty");
+    (best.cell, best.size, best.power)
+}
+
+pub fn part1(input: &str) -> Result<String> {
+    let (cell, _, _) = best_square(grid_id(input)?, GRID_SIZE, 3..=3);
+    Ok(format!("{},{}", cell.x, cell.y))
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    let (cell, size, _) = best_square(grid_id(input)?, GRID_SIZE, 1..=GRID_SIZE);
+    Ok(format!("{},{},{}", cell.x, cell.y, size))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u32 {
+        11
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
@@ -0,0 +1,206 @@
+use crate::parse::{self, LineError};
+use crate::scan;
+use crate::solution::Solution;
+use crate::Result;
+use std::collections::*;
+
+static INPUT: &str = include_str!("day12.txt");
+
+fn initial_state(input: &str) -> Result<Vec<u8>> {
+    let line = parse::lines(input)
+        .next()
+        .ok_or("missing the initial state")?;
+    let state = line.parse_with(|text| {
+        let (state,): (String,) = scan!("initial state: {}", state)(text)?;
+        // The state is the last thing on the line
+        let start = text.len() - state.len();
+        if let Some(i) = state.bytes().position(|b| b != POTTED && b != EMPTY) {
+            return Err(LineError::new(start + i + 1, "pots must be '#' or '.'"));
+        }
+        Ok(state.into_bytes())
+    })?;
+    Ok(state)
+}
+
+fn patterns(input: &str) -> Result<Vec<(Vec<u8>, u8)>> {
+    // The first line is the initial state
+    let patterns = parse::lines(input)
+        .skip(1)
+        .map(|line| {
+            line.parse_with(|text| {
+                let (pat, res): (String, char) = scan!("{} => {}", pattern, result)(text)?;
+                if pat.len() != 5 {
+                    return Err(LineError::new(1, "patterns must be 5 pots long"));
+                }
+                Ok((pat.into_bytes(), res as u8))
+            })
+        })
+        .collect::<std::result::Result<_, _>>()?;
+    Ok(patterns)
+}
+
+const POTTED: u8 = b'#';
+const EMPTY: u8 = b'.';
+
+#[derive(Default)]
+struct PotRow {
+    pots: VecDeque<u8>,
+    next: VecDeque<u8>,
+    center: usize,
+    generation: isize,
+    patterns: Vec<(Vec<u8>, u8)>,
+}
+
+impl PotRow {
+    fn begin(&self) -> isize {
+        -(self.center as isize) - 2
+    }
+
+    fn end(&self) -> isize {
+        (self.pots.len() - self.center) as isize + 2
+    }
+
+    fn get(&self, idx: isize) -> u8 {
+        let idx = self.center as isize + idx;
+        if idx < 0 || idx >= self.pots.len() as isize {
+            return EMPTY;
+        }
+        self.pots[idx as usize]
+    }
+
+    fn get_nxt(&self, idx: isize) -> u8 {
+        let idx = self.center as isize + idx;
+        if idx < 0 || idx >= self.pots.len() as isize {
+            return EMPTY;
+        }
+        self.next[idx as usize]
+    }
+
+    fn pattern_at(&self, idx: isize) -> [u8; 5] {
+        [
+            self.get(idx - 2),
+            self.get(idx - 1),
+            self.get(idx),
+            self.get(idx + 1),
+            self.get(idx + 2),
+        ]
+    }
+
+    fn set(&mut self, idx: isize, value: u8) {
+        if idx < 0 {
+            while -idx > self.center as isize {
+                self.pots.push_front(EMPTY);
+                self.next.push_front(EMPTY);
+                self.center += 1;
+            }
+        } else {
+            let absidx = self.center + idx as usize;
+            while absidx >= self.pots.len() {
+                self.pots.push_back(EMPTY);
+                self.next.push_back(EMPTY);
+            }
+        }
+
+        let idx = self.center as isize + idx;
+        self.next[idx as usize] = value;
+    }
+}
+
+fn get_res(patterns: &[(Vec<u8>, u8)], ipat: [u8; 5]) -> u8 {
+    for (pat, res) in patterns {
+        if ipat[..] == pat[..] {
+            return *res;
+        }
+    }
+    // The puzzle's example leaves out the rules that produce an empty pot
+    EMPTY
+}
+
+fn get_total(state: &PotRow) -> isize {
+    let mut total = 0;
+    for i in state.begin()..state.end() {
+        if state.get(i) == POTTED {
+            total += i;
+        }
+    }
+    total
+}
+
+fn process(state: &mut PotRow) {
+    for i in state.begin()..state.end() {
+        let ipat = state.pattern_at(i);
+        let res = get_res(&state.patterns, ipat);
+        if res != state.get(i) {
+            state.set(i, res);
+        }
+        assert_eq!(state.get_nxt(i), res, "{}", state.get(i));
+    }
+
+    state.pots.clone_from(&state.next);
+    state.generation += 1;
+}
+
+fn reset_state(state: &mut PotRow, input: &str) -> Result<()> {
+    state.pots.clear();
+    state.pots.extend(initial_state(input)?);
+    state.next.clone_from(&state.pots);
+    state.center = 0;
+    state.generation = 0;
+    state.patterns = patterns(input)?;
+    Ok(())
+}
+
+fn sum_after_20(state: &mut PotRow) -> isize {
+    while state.generation < 20 {
+        process(state);
+    }
+
+    get_total(state)
+}
+
+fn sum_after_50b(state: &mut PotRow) -> isize {
+    while state.generation < 200 {
+        process(state);
+    }
+
+    let g2k = get_total(state);
+    process(state);
+    let g2k1 = get_total(state);
+
+    let gens_left = 50_000_000_000 - state.generation;
+    let dsum = g2k1 - g2k;
+
+    g2k1 + dsum * gens_left
+}
+
+pub fn part1(input: &str) -> Result<isize> {
+    let mut state = PotRow::default();
+    reset_state(&mut state, input)?;
+    Ok(sum_after_20(&mut state))
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    let mut state = PotRow::default();
+    reset_state(&mut state, input)?;
+    Ok(sum_after_50b(&mut state))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> u32 {
+        12
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
@@ -0,0 +1,263 @@
+use crate::geom::{Direction, Direction::*, Point2i};
+use crate::grid::Grid;
+use crate::solution::Solution;
+use crate::Result;
+
+/// The tracks, with the carts taken off them, and the carts still running.
+pub struct Mine {
+    pub tracks: Grid<u8>,
+    pub carts: Vec<Cart>,
+    pub crashes: Vec<Point2i>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Cart {
+    pub pos: Point2i,
+    pub dir: Direction,
+    pub turn: usize,
+}
+
+impl Cart {
+    fn forward(&mut self) {
+        self.pos += self.dir.vector();
+    }
+}
+
+pub fn parse_grid(input: &str) -> Mine {
+    let mut carts = vec![];
+
+    let tracks = Grid::from_map(input, |pos, c| match c {
+        '|' | '-' | '/' | '\\' | '+' => c as u8,
+        '^' | 'v' | '<' | '>' => {
+            let dir = cart_direction(c as u8);
+            let turn = 0;
+
+            carts.push(Cart { pos, dir, turn });
+            straight_track(dir)
+        }
+        _ => b' ',
+    });
+
+    carts.reverse();
+
+    let crashes = Vec::with_capacity(carts.len().saturating_sub(1));
+    Mine {
+        tracks,
+        carts,
+        crashes,
+    }
+}
+
+fn cart_direction(c: u8) -> Direction {
+    match c {
+        b'^' => North,
+        b'v' => South,
+        b'<' => West,
+        b'>' => East,
+        _ => unreachable!(),
+    }
+}
+
+/// The track hidden under a cart facing `dir`.
+fn straight_track(dir: Direction) -> u8 {
+    match dir {
+        North | South => b'|',
+        East | West => b'-',
+    }
+}
+
+fn directions(c: u8, from: Direction) -> &'static [Direction] {
+    match (c, from) {
+        (b'|', North) => &[North],
+        (b'|', South) => &[South],
+        (b'|', East) => &[],
+        (b'|', West) => &[],
+
+        (b'-', North) => &[],
+        (b'-', South) => &[],
+        (b'-', East) => &[East],
+        (b'-', West) => &[West],
+
+        (b'/', North) => &[East],
+        (b'/', South) => &[West],
+        (b'/', East) => &[North],
+        (b'/', West) => &[South],
+
+        (b'\\', North) => &[West],
+        (b'\\', South) => &[East],
+        (b'\\', East) => &[South],
+        (b'\\', West) => &[North],
+
+        (b'+', North) => &[West, North, East],
+        (b'+', South) => &[East, South, West],
+        (b'+', East) => &[North, East, South],
+        (b'+', West) => &[South, West, North],
+
+        _ => &[],
+    }
+}
+
+/// Moves every cart once, in order, clearing away any that crash. It's an
+/// error for a cart to run off the end of its track, and the mine is left
+/// part way through the tick if one does.
+pub fn tick(mine: &mut Mine) -> Result<()> {
+    let mut crashes = Vec::new();
+    for i in 0..mine.carts.len() {
+        if crashes.contains(&i) {
+            continue;
+        }
+
+        let cart = mine.carts[i];
+        let track = mine.tracks[cart.pos];
+
+        let ways = directions(track, cart.dir);
+        match ways.len() {
+            1 => {
+                mine.carts[i].dir = ways[0];
+            }
+            3 => {
+                mine.carts[i].dir = ways[cart.turn % 3];
+                mine.carts[i].turn += 1;
+            }
+            _ => unreachable!("carts are only ever moved onto track"),
+        }
+
+        mine.carts[i].forward();
+
+        let cart = mine.carts[i];
+        let on_track = match mine.tracks.get(cart.pos) {
+            Some(&track) => !directions(track, cart.dir).is_empty(),
+            None => false,
+        };
+        if !on_track {
+            let from = cart.pos - cart.dir.vector();
+            return Err(format!(
+                "a cart ran off the track going from {},{} to {},{}",
+                from.x, from.y, cart.pos.x, cart.pos.y
+            )
+            .into());
+        }
+
+        for j in 0..mine.carts.len() {
+            if i != j && cart.pos == mine.carts[j].pos {
+                mine.crashes.push(cart.pos);
+                crashes.push(i);
+                crashes.push(j);
+            }
+        }
+    }
+
+    crashes.sort_by_key(|i| usize::MAX - i);
+    for crashed in crashes {
+        mine.carts.remove(crashed);
+    }
+    Ok(())
+}
+
+/// Spots the carts getting back to exactly where they were at some earlier
+/// tick, after which they'll just go round the same loop forever. Uses Brent's
+/// algorithm, so it only ever has to remember one earlier tick.
+struct LoopCheck {
+    saved: Vec<Cart>,
+    power: usize,
+    ticks: usize,
+}
+
+impl LoopCheck {
+    fn new(mine: &Mine) -> LoopCheck {
+        LoopCheck {
+            saved: carts_state(mine),
+            power: 1,
+            ticks: 0,
+        }
+    }
+
+    /// Whether the carts are somewhere they've been before.
+    fn repeated(&mut self, mine: &Mine) -> bool {
+        let carts = carts_state(mine);
+        if carts == self.saved {
+            return true;
+        }
+
+        self.ticks += 1;
+        if self.ticks == self.power {
+            self.saved = carts;
+            self.power *= 2;
+            self.ticks = 0;
+        }
+        false
+    }
+}
+
+/// The carts with the turns they've made only counted as far as what they'll
+/// do at the next crossing.
+fn carts_state(mine: &Mine) -> Vec<Cart> {
+    mine.carts
+        .iter()
+        .map(|&cart| Cart {
+            turn: cart.turn % 3,
+            ..cart
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> Result<String> {
+    let mut mine = parse_grid(input);
+    if mine.carts.len() < 2 {
+        return Err("need at least two carts for a crash".into());
+    }
+
+    let mut loop_check = LoopCheck::new(&mine);
+    let first_crash = loop {
+        tick(&mut mine)?;
+
+        if !mine.crashes.is_empty() {
+            break mine.crashes[0];
+        } else if loop_check.repeated(&mine) {
+            return Err("the carts go round forever without crashing".into());
+        }
+    };
+
+    Ok(format!("{},{}", first_crash.x, first_crash.y))
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    let mut mine = parse_grid(input);
+
+    let mut loop_check = LoopCheck::new(&mine);
+    let last_cart = loop {
+        tick(&mut mine)?;
+
+        if mine.carts.len() == 1 {
+            break mine.carts[0].pos;
+        } else if mine.carts.is_empty() {
+            return Err("every cart crashed".into());
+        } else if loop_check.repeated(&mine) {
+            return Err("the carts go round forever without all but one crashing".into());
+        }
+    };
+
+    Ok(format!("{},{}", last_cart.x, last_cart.y))
+}
+
+static INPUT: &str = include_str!("day13.txt");
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> u32 {
+        13
+    }
+
+    fn input(&self) -> &'static str {
+        INPUT
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        part1(input).map(|a| a.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(input).map(|a| a.to_string())
+    }
+}
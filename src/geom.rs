@@ -0,0 +1,273 @@
+//! Integer 2D geometry for the grid puzzles. `y` grows downwards, the way the
+//! puzzle inputs are laid out.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point2i {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vector2i {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// A rectangle that includes both corners, so a rect around a single point
+/// has an area of 1. Rects with `right < left` or `bottom < top` are empty.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Recti {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+use self::Direction::*;
+
+impl Point2i {
+    pub const ORIGIN: Point2i = Point2i { x: 0, y: 0 };
+
+    pub fn new(x: i32, y: i32) -> Point2i {
+        Point2i { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2i) -> i32 {
+        (self - other).manhattan_len()
+    }
+
+    /// The points above, right, below and left of this one.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2i> {
+        Direction::ALL.iter().map(move |&dir| self + dir.vector())
+    }
+
+    /// The 4-connected neighbours plus the diagonals, clockwise from the top
+    /// left.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2i> {
+        const OFFSETS: [(i32, i32); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ];
+        OFFSETS.iter().map(move |&off| self + Vector2i::from(off))
+    }
+}
+
+impl Vector2i {
+    pub const ZERO: Vector2i = Vector2i { x: 0, y: 0 };
+
+    pub fn new(x: i32, y: i32) -> Vector2i {
+        Vector2i { x, y }
+    }
+
+    pub fn manhattan_len(self) -> i32 {
+        self.x.abs() + self.y.abs()
+    }
+}
+
+impl Recti {
+    /// Combining anything with this gives a rect around just that point.
+    pub const EMPTY: Recti = Recti {
+        left: i32::MAX,
+        top: i32::MAX,
+        right: i32::MIN,
+        bottom: i32::MIN,
+    };
+
+    pub fn new(left: i32, top: i32, right: i32, bottom: i32) -> Recti {
+        Recti {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.right < self.left || self.bottom < self.top
+    }
+
+    pub fn width(&self) -> i32 {
+        if self.right < self.left {
+            0
+        } else {
+            self.right - self.left + 1
+        }
+    }
+
+    pub fn height(&self) -> i32 {
+        if self.bottom < self.top {
+            0
+        } else {
+            self.bottom - self.top + 1
+        }
+    }
+
+    /// The number of points in the rect. Wide enough not to overflow for any
+    /// rect of `i32` corners.
+    pub fn area(&self) -> i64 {
+        i64::from(self.width()) * i64::from(self.height())
+    }
+
+    /// The smallest rect containing this one and `p`.
+    pub fn combined_with(&self, p: impl Into<Point2i>) -> Recti {
+        let p = p.into();
+        Recti {
+            left: self.left.min(p.x),
+            top: self.top.min(p.y),
+            right: self.right.max(p.x),
+            bottom: self.bottom.max(p.y),
+        }
+    }
+
+    pub fn contains(&self, p: Point2i) -> bool {
+        p.x >= self.left && p.x <= self.right && p.y >= self.top && p.y <= self.bottom
+    }
+
+    /// Whether `p` is inside the rect and on its outermost row or column.
+    pub fn is_on_edge(&self, p: Point2i) -> bool {
+        self.contains(p)
+            && (p.x == self.left || p.x == self.right || p.y == self.top || p.y == self.bottom)
+    }
+
+    /// Every point in the rect, a row at a time from the top.
+    pub fn points(&self) -> impl Iterator<Item = Point2i> {
+        let r = *self;
+        (r.top..=r.bottom).flat_map(move |y| (r.left..=r.right).map(move |x| Point2i { x, y }))
+    }
+}
+
+impl Direction {
+    /// Clockwise from north.
+    pub const ALL: [Direction; 4] = [North, East, South, West];
+
+    pub fn vector(self) -> Vector2i {
+        match self {
+            North => Vector2i::new(0, -1),
+            East => Vector2i::new(1, 0),
+            South => Vector2i::new(0, 1),
+            West => Vector2i::new(-1, 0),
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            North => West,
+            East => North,
+            South => East,
+            West => South,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        match self {
+            North => South,
+            East => West,
+            South => North,
+            West => East,
+        }
+    }
+}
+
+impl From<(i32, i32)> for Point2i {
+    fn from((x, y): (i32, i32)) -> Point2i {
+        Point2i { x, y }
+    }
+}
+
+impl From<(i32, i32)> for Vector2i {
+    fn from((x, y): (i32, i32)) -> Vector2i {
+        Vector2i { x, y }
+    }
+}
+
+impl From<[i32; 2]> for Vector2i {
+    fn from([x, y]: [i32; 2]) -> Vector2i {
+        Vector2i { x, y }
+    }
+}
+
+impl Add<Vector2i> for Point2i {
+    type Output = Point2i;
+    fn add(self, v: Vector2i) -> Point2i {
+        Point2i::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl Sub<Vector2i> for Point2i {
+    type Output = Point2i;
+    fn sub(self, v: Vector2i) -> Point2i {
+        Point2i::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl Sub for Point2i {
+    type Output = Vector2i;
+    fn sub(self, other: Point2i) -> Vector2i {
+        Vector2i::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign<Vector2i> for Point2i {
+    fn add_assign(&mut self, v: Vector2i) {
+        *self = *self + v;
+    }
+}
+
+impl SubAssign<Vector2i> for Point2i {
+    fn sub_assign(&mut self, v: Vector2i) {
+        *self = *self - v;
+    }
+}
+
+impl Add for Vector2i {
+    type Output = Vector2i;
+    fn add(self, v: Vector2i) -> Vector2i {
+        Vector2i::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl Sub for Vector2i {
+    type Output = Vector2i;
+    fn sub(self, v: Vector2i) -> Vector2i {
+        Vector2i::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl Mul<i32> for Vector2i {
+    type Output = Vector2i;
+    fn mul(self, s: i32) -> Vector2i {
+        Vector2i::new(self.x * s, self.y * s)
+    }
+}
+
+impl Neg for Vector2i {
+    type Output = Vector2i;
+    fn neg(self) -> Vector2i {
+        Vector2i::new(-self.x, -self.y)
+    }
+}
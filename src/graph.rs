@@ -0,0 +1,214 @@
+//! Dependency graphs between named steps, where an edge from `a` to `b` means
+//! `a` has to be finished before `b` can start.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap};
+use std::error::Error;
+use std::fmt;
+
+/// A node's index in the graph it was added to.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub usize);
+
+#[derive(Clone, Debug, Default)]
+pub struct DepGraph {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    prerequisites: Vec<BTreeSet<NodeId>>,
+    dependents: Vec<BTreeSet<NodeId>>,
+}
+
+/// Steps that all (indirectly) depend on each other, so none of them can ever
+/// start. Each one has to finish before the next, and the last before the
+/// first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub steps: Vec<String>,
+}
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "the steps form a cycle: ")?;
+        for step in &self.steps {
+            write!(fmt, "{} -> ", step)?;
+        }
+        write!(fmt, "{}", self.steps.first().map_or("", |s| &s[..]))
+    }
+}
+
+impl Error for Cycle {}
+
+impl DepGraph {
+    pub fn new() -> DepGraph {
+        DepGraph::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Adds a step with no dependencies, or finds it if it's already there.
+    pub fn add_node(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = NodeId(self.names.len());
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.prerequisites.push(BTreeSet::new());
+        self.dependents.push(BTreeSet::new());
+        id
+    }
+
+    /// Records that `before` has to finish before `after` can start, adding
+    /// either of them that isn't in the graph yet.
+    pub fn add_edge(&mut self, before: &str, after: &str) -> (NodeId, NodeId) {
+        let before = self.add_node(before);
+        let after = self.add_node(after);
+        self.prerequisites[after.0].insert(before);
+        self.dependents[before.0].insert(after);
+        (before, after)
+    }
+
+    pub fn node(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).cloned()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id.0]
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.names.len()).map(NodeId)
+    }
+
+    /// The steps that have to finish before `id` can start.
+    pub fn prerequisites(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.prerequisites[id.0].iter().cloned()
+    }
+
+    /// The steps that can't start until `id` has finished.
+    pub fn dependents(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.dependents[id.0].iter().cloned()
+    }
+
+    /// Orders the steps so that each comes after everything it depends on,
+    /// picking the alphabetically first step whenever more than one could go
+    /// next.
+    pub fn topo_sort(&self) -> Result<Vec<NodeId>, Cycle> {
+        let mut waiting_on: Vec<usize> = self.prerequisites.iter().map(|p| p.len()).collect();
+        let mut ready: BinaryHeap<_> = self
+            .nodes()
+            .filter(|id| waiting_on[id.0] == 0)
+            .map(|id| Reverse((self.name(id), id)))
+            .collect();
+
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(Reverse((_, id))) = ready.pop() {
+            order.push(id);
+            for next in self.dependents(id) {
+                waiting_on[next.0] -= 1;
+                if waiting_on[next.0] == 0 {
+                    ready.push(Reverse((self.name(next), next)));
+                }
+            }
+        }
+
+        if order.len() < self.len() {
+            let stuck = waiting_on.iter().position(|&n| n > 0).unwrap();
+            return Err(self.cycle_from(NodeId(stuck), &waiting_on));
+        }
+        Ok(order)
+    }
+
+    /// A cycle in the graph, if it has one.
+    pub fn find_cycle(&self) -> Option<Cycle> {
+        self.topo_sort().err()
+    }
+
+    /// Every step still `waiting_on` something has a prerequisite that's
+    /// waiting too, so following those back from `start` has to go round in
+    /// a loop eventually.
+    fn cycle_from(&self, start: NodeId, waiting_on: &[usize]) -> Cycle {
+        let mut path = vec![start];
+        let mut seen = HashMap::new();
+        seen.insert(start, 0);
+
+        let mut current = start;
+        loop {
+            current = self
+                .prerequisites(current)
+                .find(|p| waiting_on[p.0] > 0)
+                .expect("a waiting step has a waiting prerequisite");
+            if let Some(&first) = seen.get(&current) {
+                let mut steps: Vec<String> = path[first..]
+                    .iter()
+                    .rev()
+                    .map(|&id| self.name(id).to_string())
+                    .collect();
+                // Start from the alphabetically first step, for a stable report
+                let lowest = (0..steps.len()).min_by_key(|&i| &steps[i]).unwrap();
+                steps.rotate_left(lowest);
+                return Cycle { steps };
+            }
+            seen.insert(current, path.len());
+            path.push(current);
+        }
+    }
+
+    /// How long it takes to finish every step with unlimited workers, given
+    /// how long each step takes, and the chain of steps that takes that long.
+    pub fn critical_path(
+        &self,
+        duration: impl Fn(NodeId) -> u32,
+    ) -> Result<(u32, Vec<NodeId>), Cycle> {
+        let order = self.topo_sort()?;
+
+        let mut finish = vec![0; self.len()];
+        let mut slowest_prerequisite = vec![None; self.len()];
+        for &id in &order {
+            let slowest = self.prerequisites(id).max_by_key(|p| finish[p.0]);
+            finish[id.0] = duration(id) + slowest.map_or(0, |p| finish[p.0]);
+            slowest_prerequisite[id.0] = slowest;
+        }
+
+        let last = match self.nodes().max_by_key(|id| finish[id.0]) {
+            Some(last) => last,
+            None => return Ok((0, vec![])),
+        };
+        let mut path = vec![last];
+        while let Some(prev) = slowest_prerequisite[path[path.len() - 1].0] {
+            path.push(prev);
+        }
+        path.reverse();
+        Ok((finish[last.0], path))
+    }
+
+    /// The graph in Graphviz's DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for id in self.nodes() {
+            dot += &format!("    {};\n", dot_id(self.name(id)));
+        }
+        for id in self.nodes() {
+            for next in self.dependents(id) {
+                dot += &format!(
+                    "    {} -> {};\n",
+                    dot_id(self.name(id)),
+                    dot_id(self.name(next))
+                );
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn dot_id(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
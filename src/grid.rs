@@ -0,0 +1,179 @@
+//! A rectangular grid of cells addressed by `Point2i`, with `(0, 0)` in the
+//! top left.
+
+use crate::geom::{Point2i, Recti};
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point2i) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(cell(Point2i::new(x as i32, y as i32)));
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from a map drawn with one character per cell. Lines
+    /// shorter than the longest one are padded with spaces, and blank lines at
+    /// the end are ignored.
+    pub fn from_map(map: &str, mut cell: impl FnMut(Point2i, char) -> T) -> Grid<T> {
+        let mut lines: Vec<&str> = map.lines().collect();
+        while lines.last().is_some_and(|l| l.trim().is_empty()) {
+            lines.pop();
+        }
+
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let mut chars = line.chars();
+            for x in 0..width {
+                let c = chars.next().unwrap_or(' ');
+                cells.push(cell(Point2i::new(x as i32, y as i32), c));
+            }
+        }
+
+        Grid {
+            width,
+            height: lines.len(),
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The rect covering every cell.
+    pub fn bounds(&self) -> Recti {
+        Recti::new(0, 0, self.width as i32 - 1, self.height as i32 - 1)
+    }
+
+    pub fn contains(&self, p: Point2i) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn offset(&self, p: Point2i) -> Option<usize> {
+        if self.contains(p) {
+            Some(p.y as usize * self.width + p.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, p: Point2i) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point2i) -> Option<&mut T> {
+        match self.offset(p) {
+            Some(i) => Some(&mut self.cells[i]),
+            None => None,
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` doesn't accept 0, but a grid with no columns has no cells
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of range", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Every point in the grid, a row at a time.
+    pub fn points(&self) -> impl Iterator<Item = Point2i> {
+        self.bounds().points()
+    }
+
+    /// Every cell and where it is, a row at a time.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2i, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The 4-connected neighbours of `p` that are inside the grid.
+    pub fn neighbours4(&self, p: Point2i) -> impl Iterator<Item = (Point2i, &T)> {
+        p.neighbours4()
+            .filter_map(move |n| self.get(n).map(|c| (n, c)))
+    }
+
+    /// The 8-connected neighbours of `p` that are inside the grid.
+    pub fn neighbours8(&self, p: Point2i) -> impl Iterator<Item = (Point2i, &T)> {
+        p.neighbours8()
+            .filter_map(move |n| self.get(n).map(|c| (n, c)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point2i> for Grid<T> {
+    type Output = T;
+    fn index(&self, p: Point2i) -> &T {
+        match self.offset(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{:?} is outside a {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2i> for Grid<T> {
+    fn index_mut(&mut self, p: Point2i) -> &mut T {
+        match self.offset(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{:?} is outside a {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+/// Draws the grid back out as a character map, one line per row.
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for &c in row {
+                write!(fmt, "{}", c.into())?;
+            }
+            writeln!(fmt)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Where a day's puzzle text comes from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Input {
+    /// The input checked in next to the solution.
+    Embedded,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Input {
+    /// `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Input {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(path.into()),
+        }
+    }
+
+    /// Accepts either `--input <path>` or a bare `<path>`. No arguments
+    /// falls back to the embedded input.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Input, String> {
+        let mut args = args.into_iter();
+        let input = match args.next() {
+            None => return Ok(Input::Embedded),
+            Some(ref flag) if flag == "--input" => match args.next() {
+                Some(arg) => Input::from_arg(&arg),
+                None => return Err("--input requires a path".into()),
+            },
+            Some(arg) => Input::from_arg(&arg),
+        };
+
+        if let Some(extra) = args.next() {
+            return Err(format!("unexpected argument '{}'", extra));
+        }
+
+        Ok(input)
+    }
+
+    pub fn load(&self, embedded: &'static str) -> io::Result<Cow<'static, str>> {
+        match self {
+            Input::Embedded => Ok(Cow::Borrowed(embedded)),
+            Input::File(path) => fs::read_to_string(path).map(Cow::Owned),
+            Input::Stdin => {
+                let mut data = String::new();
+                io::stdin().read_to_string(&mut data)?;
+                Ok(Cow::Owned(data))
+            }
+        }
+    }
+}
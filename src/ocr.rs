@@ -0,0 +1,89 @@
+//! Reads messages spelt out in the big 6x10 capital letters that some puzzles
+//! draw, like the one the lights in day 10 line up into.
+
+use crate::geom::{Point2i, Recti};
+
+use std::collections::HashSet;
+
+pub const GLYPH_WIDTH: i32 = 6;
+pub const GLYPH_HEIGHT: i32 = 10;
+/// The number of empty columns between letters.
+pub const GLYPH_SPACING: i32 = 2;
+
+/// Every letter that's been seen in a puzzle so far, in the same order as in
+/// `FONT`.
+const LETTERS: &str = "ABCEFGHJKLNPRXZ";
+
+/// The letters drawn side by side, the same way they appear in messages.
+#[rustfmt::skip]
+const FONT: [&str; 10] = [
+    "  ##    #####    ####   ######  ######   ####   #    #     ###  #    #  #       #    #  #####   #####   #    #  ######",
+    " #  #   #    #  #    #  #       #       #    #  #    #      #   #   #   #       ##   #  #    #  #    #  #    #       #",
+    "#    #  #    #  #       #       #       #       #    #      #   #  #    #       ##   #  #    #  #    #   #  #        #",
+    "#    #  #    #  #       #       #       #       #    #      #   # #     #       # #  #  #    #  #    #   #  #       # ",
+    "#    #  #####   #       #####   #####   #       ######      #   ##      #       # #  #  #####   #####     ##       #  ",
+    "######  #    #  #       #       #       #  ###  #    #      #   ##      #       #  # #  #       #  #      ##      #   ",
+    "#    #  #    #  #       #       #       #    #  #    #      #   # #     #       #  # #  #       #   #    #  #    #    ",
+    "#    #  #    #  #       #       #       #    #  #    #  #   #   #  #    #       #   ##  #       #   #    #  #   #     ",
+    "#    #  #    #  #    #  #       #       #   ##  #    #  #   #   #   #   #       #   ##  #       #    #  #    #  #     ",
+    "#    #  #####    ####   ######  #        ### #  #    #   ###    #    #  ######  #    #  #       #    #  #    #  ######",
+];
+
+/// The points lit by the `index`th letter of the font, if it were drawn with
+/// its top left corner at `origin`.
+fn glyph(index: usize, origin: Point2i) -> impl Iterator<Item = Point2i> {
+    let left = index * (GLYPH_WIDTH + GLYPH_SPACING) as usize;
+    FONT.iter().zip(0..).flat_map(move |(row, y)| {
+        row[left..left + GLYPH_WIDTH as usize]
+            .bytes()
+            .zip(0..)
+            .filter(|&(c, _)| c == b'#')
+            .map(move |(_, x)| Point2i::new(origin.x + x, origin.y + y))
+    })
+}
+
+/// Whether the points inside the glyph-sized box at `origin` are exactly the
+/// ones that draw the `index`th letter.
+fn matches(lit: &HashSet<Point2i>, origin: Point2i, index: usize) -> bool {
+    let bounds = Recti::new(
+        origin.x,
+        origin.y,
+        origin.x + GLYPH_WIDTH - 1,
+        origin.y + GLYPH_HEIGHT - 1,
+    );
+    let drawn: HashSet<Point2i> = glyph(index, origin).collect();
+    bounds
+        .points()
+        .all(|p| lit.contains(&p) == drawn.contains(&p))
+}
+
+/// Reads the letters spelt out by a set of lit points, or `None` if they
+/// aren't a row of letters that are all in the font.
+pub fn read_message(points: impl IntoIterator<Item = Point2i>) -> Option<String> {
+    let lit: HashSet<Point2i> = points.into_iter().collect();
+    let bounds = lit.iter().fold(Recti::EMPTY, |r, &p| r.combined_with(p));
+    let stride = GLYPH_WIDTH + GLYPH_SPACING;
+    if bounds.is_empty()
+        || bounds.height() != GLYPH_HEIGHT
+        || (bounds.width() + GLYPH_SPACING) % stride != 0
+    {
+        return None;
+    }
+
+    let letters = (bounds.width() + GLYPH_SPACING) / stride;
+    let mut message = String::new();
+    let mut covered = 0;
+    for i in 0..letters {
+        let origin = Point2i::new(bounds.left + i * stride, bounds.top);
+        let index = (0..LETTERS.len()).find(|&index| matches(&lit, origin, index))?;
+        message.push(LETTERS.as_bytes()[index] as char);
+        covered += glyph(index, origin).count();
+    }
+
+    // Anything else lit up must be between the letters, so it isn't text
+    if covered == lit.len() {
+        Some(message)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,189 @@
+//! Line-oriented input parsing that reports where it went wrong instead of
+//! skipping the offending line.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A failure inside a single line. `column` is a 1-based byte column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineError {
+    pub column: usize,
+    pub message: String,
+}
+
+impl LineError {
+    pub fn new(column: usize, message: impl Into<String>) -> LineError {
+        LineError {
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+/// A `LineError` together with the line it happened on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            fmt,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(fmt, "    {}", self.text)?;
+        write!(fmt, "    {:>1$}", "^", self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+/// A non-blank line of input and its 1-based line number.
+#[derive(Copy, Clone, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn parse_with<T>(
+        self,
+        parse: impl FnOnce(&'a str) -> Result<T, LineError>,
+    ) -> Result<T, ParseError> {
+        parse(self.text).map_err(|e| ParseError {
+            line: self.number,
+            column: e.column,
+            text: self.text.into(),
+            message: e.message,
+        })
+    }
+}
+
+/// The non-blank lines of `input`, with trailing whitespace removed.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line {
+            number: i + 1,
+            text: text.trim_end(),
+        })
+        .filter(|line| !line.text.is_empty())
+}
+
+/// Parses every non-blank line of `input`, stopping at the first failure.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, LineError>,
+) -> Result<Vec<T>, ParseError> {
+    lines(input)
+        .map(|line| line.parse_with(&mut parse))
+        .collect()
+}
+
+/// A piece of a line captured by a `{}` placeholder.
+#[derive(Copy, Clone, Debug)]
+pub struct Field<'a> {
+    pub text: &'a str,
+    pub column: usize,
+}
+
+impl<'a> Field<'a> {
+    pub fn parse<T: FromStr>(self, name: &str) -> Result<T, LineError> {
+        self.text.parse().map_err(|_| {
+            LineError::new(
+                self.column,
+                format!("invalid value for `{}`: '{}'", name, self.text),
+            )
+        })
+    }
+}
+
+/// Matches `line` against `pattern`, where every `{}` in the pattern captures
+/// the text up to the literal that follows it. Captures have surrounding
+/// whitespace trimmed and may not be empty.
+pub fn scan_fields<'a>(
+    pattern: &str,
+    line: &'a str,
+    names: &[&str],
+) -> Result<Vec<Field<'a>>, LineError> {
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    assert_eq!(
+        literals.len() - 1,
+        names.len(),
+        "pattern '{}' doesn't have a placeholder for every field",
+        pattern
+    );
+
+    let mut pos = expect_literal(line, 0, literals[0])?;
+    let mut fields = Vec::with_capacity(names.len());
+
+    for (name, &literal) in names.iter().zip(&literals[1..]) {
+        let rest = &line[pos..];
+        let len = if literal.is_empty() {
+            rest.len()
+        } else {
+            match rest.find(literal) {
+                Some(len) => len,
+                None => {
+                    return Err(LineError::new(
+                        pos + 1,
+                        format!("expected '{}' after `{}`", literal, name),
+                    ))
+                }
+            }
+        };
+
+        let raw = &rest[..len];
+        let text = raw.trim();
+        let column = pos + (raw.len() - raw.trim_start().len()) + 1;
+        if text.is_empty() {
+            return Err(LineError::new(column, format!("missing `{}`", name)));
+        }
+
+        fields.push(Field { text, column });
+        pos = expect_literal(line, pos + len, literal)?;
+    }
+
+    if pos < line.trim_end().len() {
+        return Err(LineError::new(pos + 1, "unexpected trailing text"));
+    }
+
+    Ok(fields)
+}
+
+fn expect_literal(line: &str, pos: usize, literal: &str) -> Result<usize, LineError> {
+    if line[pos..].starts_with(literal) {
+        Ok(pos + literal.len())
+    } else {
+        Err(LineError::new(pos + 1, format!("expected '{}'", literal)))
+    }
+}
+
+/// Builds a closure that parses a line according to a `{}` pattern, returning
+/// a `Result` with a tuple of the captured fields. The names are only used in
+/// error messages; the field types are inferred from how the tuple is used.
+///
+/// ```
+/// use aoc2018::scan;
+///
+/// let claim: (i32, i32, i32, i32, i32) =
+///     scan!("#{} @ {},{}: {}x{}", id, x, y, w, h)("#1 @ 3,2: 5x4").unwrap();
+/// assert_eq!(claim, (1, 3, 2, 5, 4));
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($pattern:expr, $($name:ident),+) => {
+        |line: &str| -> ::std::result::Result<_, $crate::parse::LineError> {
+            let fields = $crate::parse::scan_fields($pattern, line, &[$(stringify!($name)),+])?;
+            let mut fields = fields.into_iter();
+            Ok(($(fields.next().unwrap().parse(stringify!($name))?,)+))
+        }
+    };
+}
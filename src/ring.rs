@@ -0,0 +1,172 @@
+//! A circular doubly-linked list with a cursor, for simulations where things
+//! keep getting added and removed around a circle.
+
+/// A circle of values with one of them marked as current. Moving "forwards"
+/// goes clockwise.
+///
+/// The links are indices into a single `Vec`, which is kept packed by moving
+/// the last node into the hole whenever one is removed.
+#[derive(Clone, Debug)]
+pub struct Ring<T> {
+    nodes: Vec<Node<T>>,
+    current: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    value: T,
+    prev: usize,
+    next: usize,
+}
+
+impl<T> Ring<T> {
+    pub fn new() -> Ring<T> {
+        Ring::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Ring<T> {
+        Ring {
+            nodes: Vec::with_capacity(capacity),
+            current: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        self.nodes.get(self.current).map(|n| &n.value)
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.nodes.get_mut(self.current).map(|n| &mut n.value)
+    }
+
+    /// Moves the cursor `n` places clockwise, or anticlockwise if `n` is
+    /// negative. Whole turns are skipped and it goes whichever way round is
+    /// shorter, so this never takes more than `len / 2` steps.
+    pub fn rotate(&mut self, n: isize) {
+        if self.nodes.len() < 2 {
+            return;
+        }
+
+        let len = self.nodes.len();
+        let steps = n.rem_euclid(len as isize) as usize;
+        if steps <= len / 2 {
+            for _ in 0..steps {
+                self.current = self.nodes[self.current].next;
+            }
+        } else {
+            for _ in steps..len {
+                self.current = self.nodes[self.current].prev;
+            }
+        }
+    }
+
+    /// Inserts `value` clockwise of the current value and makes it current.
+    /// In an empty ring it becomes the only value.
+    pub fn insert_after(&mut self, value: T) {
+        let new = self.nodes.len();
+        if self.nodes.is_empty() {
+            self.nodes.push(Node {
+                value,
+                prev: new,
+                next: new,
+            });
+        } else {
+            let prev = self.current;
+            let next = self.nodes[prev].next;
+            self.nodes.push(Node { value, prev, next });
+            self.nodes[prev].next = new;
+            self.nodes[next].prev = new;
+        }
+        self.current = new;
+    }
+
+    /// Takes out the current value, leaving the cursor on the value that was
+    /// clockwise of it.
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.nodes.is_empty() {
+            return None;
+        }
+
+        let removed = self.current;
+        let (prev, next) = (self.nodes[removed].prev, self.nodes[removed].next);
+        self.nodes[prev].next = next;
+        self.nodes[next].prev = prev;
+        self.current = next;
+
+        // The last node is about to be moved into the removed one's slot
+        let last = self.nodes.len() - 1;
+        if removed != last {
+            let (last_prev, last_next) = (self.nodes[last].prev, self.nodes[last].next);
+            self.nodes[last_prev].next = removed;
+            self.nodes[last_next].prev = removed;
+            if self.current == last {
+                self.current = removed;
+            }
+        }
+
+        let node = self.nodes.swap_remove(removed);
+        if self.nodes.is_empty() {
+            self.current = 0;
+        }
+        Some(node.value)
+    }
+
+    /// Every value once, clockwise from the current one.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            ring: self,
+            node: self.current,
+            remaining: self.nodes.len(),
+        }
+    }
+}
+
+impl<T> Default for Ring<T> {
+    fn default() -> Ring<T> {
+        Ring::new()
+    }
+}
+
+pub struct Iter<'a, T> {
+    ring: &'a Ring<T>,
+    node: usize,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let node = &self.ring.nodes[self.node];
+        self.node = node.next;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> IntoIterator for &'a Ring<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
@@ -0,0 +1,85 @@
+use crate::input::Input;
+use crate::Result;
+
+use std::borrow::Cow;
+use std::process::exit;
+use std::time::{Duration, Instant};
+
+/// One day's puzzle. Each part returns its answer, formatted the way the
+/// puzzle expects it to be submitted, or an error if the input doesn't make
+/// sense.
+pub trait Solution: Sync {
+    fn day(&self) -> u32;
+
+    /// The puzzle input embedded in the binary, used when no other input is
+    /// given on the command line.
+    fn input(&self) -> &'static str;
+
+    fn part1(&self, input: &str) -> Result<String>;
+    fn part2(&self, input: &str) -> Result<String>;
+}
+
+/// Runs a single part of a day's puzzle, returning the answer and how long it
+/// took.
+pub fn time_part(solution: &dyn Solution, part: u32, input: &str) -> (Result<String>, Duration) {
+    let start = Instant::now();
+    let answer = match part {
+        1 => solution.part1(input),
+        2 => solution.part2(input),
+        _ => panic!("day {} has no part {}", solution.day(), part),
+    };
+    (answer, start.elapsed())
+}
+
+/// Loads the input for `solution`, exiting with a message if it can't be read.
+pub fn load_input(solution: &dyn Solution, input: &Input) -> Cow<'static, str> {
+    match input.load(solution.input()) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("failed to read input for day {}: {}", solution.day(), e);
+            exit(1);
+        }
+    }
+}
+
+/// Loads the input named on the command line, see `Input::from_args`.
+pub fn input_from_args(solution: &dyn Solution) -> Cow<'static, str> {
+    match Input::from_args(std::env::args().skip(1)) {
+        Ok(input) => load_input(solution, &input),
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("usage: day{:02} [--input] [<path>|-]", solution.day());
+            exit(1);
+        }
+    }
+}
+
+/// Prints an answer to stdout, or the error to stderr. Returns whether there
+/// was an answer to print.
+pub fn print_answer(answer: &Result<String>) -> bool {
+    match answer {
+        Ok(answer) => {
+            println!("{}", answer);
+            true
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            false
+        }
+    }
+}
+
+/// Runs both parts, the way each day's standalone binary always has.
+pub fn run(solution: &dyn Solution) {
+    let input = input_from_args(solution);
+
+    println!("Part 1:");
+    let ok1 = print_answer(&solution.part1(&input));
+
+    println!("Part 2:");
+    let ok2 = print_answer(&solution.part2(&input));
+
+    if !(ok1 && ok2) {
+        exit(1);
+    }
+}
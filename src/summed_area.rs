@@ -0,0 +1,81 @@
+//! Summed-area tables, for adding up any rectangle of a grid in constant time.
+
+use crate::geom::{Point2i, Recti};
+use crate::grid::Grid;
+
+use std::ops::{Add, Sub};
+
+/// Holds, for every point, the sum of all the values above and to the left of
+/// it. The table has an extra row and column of zeros along the top and left so
+/// that rects touching the edges don't need special cases.
+#[derive(Clone, Debug)]
+pub struct SummedAreaTable<T> {
+    width: usize,
+    height: usize,
+    sums: Vec<T>,
+}
+
+impl<T> SummedAreaTable<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn from_fn(width: usize, height: usize, mut value: impl FnMut(Point2i) -> T) -> Self {
+        let stride = width + 1;
+        let mut sums = vec![T::default(); stride * (height + 1)];
+        for y in 0..height {
+            let mut row = T::default();
+            for x in 0..width {
+                row = row + value(Point2i::new(x as i32, y as i32));
+                sums[(y + 1) * stride + x + 1] = sums[y * stride + x + 1] + row;
+            }
+        }
+        SummedAreaTable {
+            width,
+            height,
+            sums,
+        }
+    }
+
+    pub fn from_grid(grid: &Grid<T>) -> Self {
+        SummedAreaTable::from_fn(grid.width(), grid.height(), |p| grid[p])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The sum of every value in the cells `0..x` and `0..y`.
+    fn prefix(&self, x: usize, y: usize) -> T {
+        self.sums[y * (self.width + 1) + x]
+    }
+
+    /// The sum of every value inside `rect`, corners included. Empty rects add
+    /// up to `T::default()`.
+    ///
+    /// Panics if a non-empty `rect` reaches outside the table.
+    pub fn rect_sum(&self, rect: Recti) -> T {
+        if rect.is_empty() {
+            return T::default();
+        }
+        assert!(
+            rect.left >= 0
+                && rect.top >= 0
+                && (rect.right as usize) < self.width
+                && (rect.bottom as usize) < self.height,
+            "{:?} is outside a {}x{} table",
+            rect,
+            self.width,
+            self.height
+        );
+
+        let (left, top) = (rect.left as usize, rect.top as usize);
+        let (right, bottom) = (rect.right as usize + 1, rect.bottom as usize + 1);
+        self.prefix(right, bottom) + self.prefix(left, top)
+            - self.prefix(left, bottom)
+            - self.prefix(right, top)
+    }
+}
@@ -0,0 +1,80 @@
+//! Drawing for the day 13 mine cart visualisation, independent of what ends
+//! up putting pixels on the screen.
+
+use crate::days::day13::{Cart, Mine};
+use crate::geom::{Direction::*, Point2i};
+
+use std::time::Duration;
+
+pub mod software;
+pub mod terminal;
+
+pub const BGCOLOR: u32 = 0xFF_FF_FF;
+pub const TRACK_COLOR: u32 = 0x77_77_77;
+pub const CART_COLOR: u32 = 0xFF_00_7F;
+
+/// How long each tick is shown for until the speed is changed.
+pub const DEFAULT_STEP: Duration = Duration::from_millis(500);
+
+/// Shortens the time between ticks, by less as it gets short.
+pub fn faster(step: Duration) -> Duration {
+    if step > Duration::from_millis(100) {
+        step - Duration::from_millis(50)
+    } else if step > Duration::from_millis(10) {
+        step - Duration::from_millis(10)
+    } else {
+        step
+    }
+}
+
+/// The opposite of `faster`.
+pub fn slower(step: Duration) -> Duration {
+    if step < Duration::from_millis(100) {
+        step + Duration::from_millis(10)
+    } else {
+        step + Duration::from_millis(50)
+    }
+}
+
+/// Something that can draw the pieces of a day 13 frame. Positions are in
+/// grid cells; backends decide how big a cell is.
+pub trait Renderer {
+    /// Fills the whole frame with `BGCOLOR`.
+    fn clear(&mut self);
+
+    /// Draws one piece of track. `left` and `right` are the tracks next to it,
+    /// which decide which way a curve bends.
+    fn draw_track(&mut self, track: u8, left: u8, right: u8, x: usize, y: usize);
+
+    fn draw_cart(&mut self, cart: &Cart);
+
+    fn draw_crash(&mut self, crash: Point2i);
+}
+
+/// Whether a curve should bend towards a neighbouring piece of track.
+pub fn joins(neighbour: u8) -> bool {
+    ![b' ', b'|'].contains(&neighbour)
+}
+
+/// Clears the frame and draws every piece of track, but no carts.
+pub fn draw_map(mine: &Mine, r: &mut impl Renderer) {
+    r.clear();
+
+    let tracks = &mine.tracks;
+    for (p, &track) in tracks.iter() {
+        let left = tracks.get(p + West.vector()).cloned().unwrap_or(b' ');
+        let right = tracks.get(p + East.vector()).cloned().unwrap_or(b' ');
+        r.draw_track(track, left, right, p.x as usize, p.y as usize);
+    }
+}
+
+/// Draws the carts still running and every crash so far.
+pub fn draw_carts(mine: &Mine, r: &mut impl Renderer) {
+    for cart in mine.carts.iter() {
+        r.draw_cart(cart);
+    }
+
+    for &crash in mine.crashes.iter() {
+        r.draw_crash(crash);
+    }
+}
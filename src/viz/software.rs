@@ -0,0 +1,214 @@
+//! A renderer that rasterises frames in memory and writes them out as PPM
+//! images, so the carts can be watched without a window.
+
+use super::{draw_carts, draw_map, joins, Renderer, BGCOLOR, CART_COLOR, TRACK_COLOR};
+use crate::days::day13::{tick, Cart, Mine};
+use crate::geom::{Direction::*, Point2i};
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// How wide track and crash lines are, in cells.
+const LINE_WIDTH: f32 = 0.2;
+
+/// An RGB image covering a grid of cells, with half a cell of margin on every
+/// side.
+#[derive(Clone)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    scale: usize,
+    pixels: Vec<u32>,
+}
+
+impl Canvas {
+    /// A canvas for a grid `columns` wide and `rows` high, `scale` pixels to
+    /// a cell.
+    pub fn new(columns: usize, rows: usize, scale: usize) -> Canvas {
+        let width = (columns + 1) * scale;
+        let height = (rows + 1) * scale;
+        Canvas {
+            width,
+            height,
+            scale,
+            pixels: vec![BGCOLOR; width * height],
+        }
+    }
+
+    /// A canvas big enough for `mine`'s tracks.
+    pub fn for_mine(mine: &Mine, scale: usize) -> Canvas {
+        Canvas::new(mine.tracks.width(), mine.tracks.height(), scale)
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The colour at a pixel, as `0xRRGGBB`.
+    pub fn pixel(&self, x: usize, y: usize) -> u32 {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        for &p in &self.pixels {
+            out.write_all(&[(p >> 16) as u8, (p >> 8) as u8, p as u8])?;
+        }
+        out.flush()
+    }
+
+    /// Sets every pixel whose centre lies within `bounds` (in cells) and
+    /// satisfies `inside`.
+    fn fill(&mut self, bounds: [f32; 4], color: u32, inside: impl Fn(f32, f32) -> bool) {
+        let scale = self.scale as f32;
+        let first = |c: f32| (c * scale).floor().max(0.0) as usize;
+        let last = |c: f32, size: usize| ((c * scale).ceil().max(0.0) as usize).min(size);
+        let [left, top, right, bottom] = bounds;
+
+        for py in first(top)..last(bottom, self.height) {
+            for px in first(left)..last(right, self.width) {
+                let x = (px as f32 + 0.5) / scale;
+                let y = (py as f32 + 0.5) / scale;
+                if x >= left && x <= right && y >= top && y <= bottom && inside(x, y) {
+                    self.pixels[py * self.width + px] = color;
+                }
+            }
+        }
+    }
+
+    /// Half of `LINE_WIDTH`, but never thinner than a pixel so that lines
+    /// don't fall between pixel centres at small scales.
+    fn half_width(&self) -> f32 {
+        (LINE_WIDTH / 2.0).max(0.5 / self.scale as f32)
+    }
+
+    fn draw_line(&mut self, a: (f32, f32), b: (f32, f32), color: u32) {
+        let r = self.half_width();
+        let bounds = [
+            a.0.min(b.0) - r,
+            a.1.min(b.1) - r,
+            a.0.max(b.0) + r,
+            a.1.max(b.1) + r,
+        ];
+        self.fill(bounds, color, |x, y| segment_distance(a, b, (x, y)) <= r);
+    }
+
+    /// A quarter circle around one corner of the cell at `(x, y)`, joining the
+    /// middles of the two edges that meet there.
+    fn draw_curve(&mut self, x: f32, y: f32, corner: (f32, f32), color: u32) {
+        let r = self.half_width();
+        let bounds = [x, y, x + 1.0, y + 1.0];
+        self.fill(bounds, color, |px, py| {
+            let dist = (px - corner.0).hypot(py - corner.1);
+            (dist - 0.5).abs() <= r
+        });
+    }
+
+    fn fill_ellipse(&mut self, center: (f32, f32), rx: f32, ry: f32, color: u32) {
+        let bounds = [center.0 - rx, center.1 - ry, center.0 + rx, center.1 + ry];
+        self.fill(bounds, color, |x, y| {
+            let dx = (x - center.0) / rx;
+            let dy = (y - center.1) / ry;
+            dx * dx + dy * dy <= 1.0
+        });
+    }
+}
+
+fn segment_distance(a: (f32, f32), b: (f32, f32), p: (f32, f32)) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 == 0.0 {
+        0.0
+    } else {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len2).clamp(0.0, 1.0)
+    };
+    (p.0 - (a.0 + t * dx)).hypot(p.1 - (a.1 + t * dy))
+}
+
+impl Renderer for Canvas {
+    fn clear(&mut self) {
+        for p in self.pixels.iter_mut() {
+            *p = BGCOLOR;
+        }
+    }
+
+    fn draw_track(&mut self, track: u8, left: u8, right: u8, x: usize, y: usize) {
+        let x = x as f32 + 0.5;
+        let y = y as f32 + 0.5;
+        match track {
+            b'-' => self.draw_line((x, y + 0.5), (x + 1.0, y + 0.5), TRACK_COLOR),
+            b'|' => self.draw_line((x + 0.5, y), (x + 0.5, y + 1.0), TRACK_COLOR),
+            b'/' => {
+                if joins(left) {
+                    self.draw_curve(x, y, (x, y), TRACK_COLOR);
+                }
+                if joins(right) {
+                    self.draw_curve(x, y, (x + 1.0, y + 1.0), TRACK_COLOR);
+                }
+            }
+            b'\\' => {
+                if joins(left) {
+                    self.draw_curve(x, y, (x, y + 1.0), TRACK_COLOR);
+                }
+                if joins(right) {
+                    self.draw_curve(x, y, (x + 1.0, y), TRACK_COLOR);
+                }
+            }
+            b'+' => {
+                self.draw_line((x, y + 0.5), (x + 1.0, y + 0.5), TRACK_COLOR);
+                self.draw_line((x + 0.5, y), (x + 0.5, y + 1.0), TRACK_COLOR);
+            }
+            _ => {}
+        }
+    }
+
+    fn draw_cart(&mut self, cart: &Cart) {
+        let x = cart.pos.x as f32 + 0.5;
+        let y = cart.pos.y as f32 + 0.5;
+
+        self.fill_ellipse((x + 0.5, y + 0.5), 0.6, 0.6, CART_COLOR);
+        match cart.dir {
+            North => self.fill_ellipse((x + 0.5, y + 0.25), 0.3, 0.6, CART_COLOR),
+            South => self.fill_ellipse((x + 0.5, y + 0.75), 0.3, 0.6, CART_COLOR),
+            East => self.fill_ellipse((x + 0.75, y + 0.5), 0.6, 0.3, CART_COLOR),
+            West => self.fill_ellipse((x + 0.25, y + 0.5), 0.6, 0.3, CART_COLOR),
+        }
+    }
+
+    fn draw_crash(&mut self, crash: Point2i) {
+        let x = crash.x as f32 + 0.5;
+        let y = crash.y as f32 + 0.5;
+
+        self.draw_line((x, y), (x + 1.0, y + 1.0), CART_COLOR);
+        self.draw_line((x + 1.0, y), (x, y + 1.0), CART_COLOR);
+    }
+}
+
+/// Runs `mine` for `frames` ticks, writing a picture of every tick into `dir`
+/// as `frame0000.ppm`, `frame0001.ppm` and so on.
+pub fn record(mine: &mut Mine, frames: usize, scale: usize, dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+
+    let mut map = Canvas::for_mine(mine, scale);
+    draw_map(mine, &mut map);
+
+    let mut frame = map.clone();
+    for i in 0..frames {
+        frame.clone_from(&map);
+        draw_carts(mine, &mut frame);
+
+        let file = File::create(dir.join(format!("frame{:04}.ppm", i)))?;
+        frame.write_ppm(BufWriter::new(file))?;
+
+        tick(mine).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    }
+
+    Ok(())
+}
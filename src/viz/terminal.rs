@@ -0,0 +1,248 @@
+//! Plays the carts back in a terminal using ANSI escapes, for watching over
+//! SSH. Keys are read from `/dev/tty`, which `stty` switches out of line
+//! buffering while the animation runs.
+
+use super::{draw_carts, draw_map, faster, slower, Renderer, DEFAULT_STEP};
+use crate::days::day13::{parse_grid, tick, Cart, Mine};
+use crate::geom::{Direction::*, Point2i};
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// An SGR parameter string, as in `\x1b[<style>m`.
+type Style = &'static str;
+
+const PLAIN: Style = "0";
+const STRAIGHT: Style = "90";
+const CURVE: Style = "36";
+const INTERSECTION: Style = "33";
+const CART: Style = "1;35";
+const CRASH: Style = "1;31";
+
+const HELP: &str = "r reset  w/s speed  z reset speed  space pause  n step  q quit";
+
+/// A grid of coloured characters, one for each cell of the map.
+#[derive(Clone)]
+pub struct Screen {
+    width: usize,
+    height: usize,
+    cells: Vec<(u8, Style)>,
+}
+
+impl Screen {
+    pub fn for_mine(mine: &Mine) -> Screen {
+        let width = mine.tracks.width();
+        let height = mine.tracks.height();
+        Screen {
+            width,
+            height,
+            cells: vec![(b' ', PLAIN); width * height],
+        }
+    }
+
+    fn set(&mut self, x: usize, y: usize, c: u8, style: Style) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = (c, style);
+        }
+    }
+
+    /// The screen as lines of text, with escapes to colour it if `color` is
+    /// set.
+    pub fn render(&self, color: bool) -> String {
+        let mut out = String::with_capacity(self.cells.len() * 2);
+        for row in self.cells.chunks(self.width.max(1)) {
+            let mut current = PLAIN;
+            for &(c, style) in row {
+                if color && style != current {
+                    out.push_str("\x1b[");
+                    out.push_str(style);
+                    out.push('m');
+                    current = style;
+                }
+                out.push(c as char);
+            }
+            if color && current != PLAIN {
+                out.push_str("\x1b[0m");
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl Renderer for Screen {
+    fn clear(&mut self) {
+        for cell in self.cells.iter_mut() {
+            *cell = (b' ', PLAIN);
+        }
+    }
+
+    fn draw_track(&mut self, track: u8, _left: u8, _right: u8, x: usize, y: usize) {
+        let style = match track {
+            b'-' | b'|' => STRAIGHT,
+            b'/' | b'\\' => CURVE,
+            b'+' => INTERSECTION,
+            _ => return,
+        };
+        self.set(x, y, track, style);
+    }
+
+    fn draw_cart(&mut self, cart: &Cart) {
+        let c = match cart.dir {
+            North => b'^',
+            South => b'v',
+            East => b'>',
+            West => b'<',
+        };
+        self.set(cart.pos.x as usize, cart.pos.y as usize, c, CART);
+    }
+
+    fn draw_crash(&mut self, crash: Point2i) {
+        self.set(crash.x as usize, crash.y as usize, b'X', CRASH);
+    }
+}
+
+/// Puts the terminal into a mode where keys arrive as they're pressed, on an
+/// alternate screen with the cursor hidden. Dropping it puts everything back.
+struct RawMode {
+    tty: File,
+}
+
+impl RawMode {
+    fn enter() -> io::Result<RawMode> {
+        let tty = OpenOptions::new().read(true).write(true).open("/dev/tty")?;
+        stty(&tty, &["-icanon", "-echo"])?;
+
+        print!("\x1b[?1049h\x1b[?25l");
+        io::stdout().flush()?;
+        Ok(RawMode { tty })
+    }
+
+    /// Sends every byte typed into the terminal down a channel.
+    fn keys(&self) -> io::Result<Receiver<u8>> {
+        let mut tty = self.tty.try_clone()?;
+        let (send, recv) = mpsc::channel();
+        thread::spawn(move || {
+            let mut buf = [0; 16];
+            while let Ok(len) = tty.read(&mut buf) {
+                if len == 0 || buf[..len].iter().any(|&b| send.send(b).is_err()) {
+                    break;
+                }
+            }
+        });
+        Ok(recv)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        let _ = io::stdout().flush();
+        let _ = stty(&self.tty, &["icanon", "echo"]);
+    }
+}
+
+fn stty(tty: &File, args: &[&str]) -> io::Result<()> {
+    let status = Command::new("stty")
+        .args(args)
+        .stdin(tty.try_clone()?)
+        .status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other("stty failed"))
+    }
+}
+
+fn draw_frame(screen: &Screen, status: &str) -> io::Result<()> {
+    let mut frame = String::from("\x1b[H");
+    frame.push_str(&screen.render(true));
+    frame.push_str(status);
+    frame.push_str("\x1b[K\n");
+    frame.push_str(HELP);
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    stdout.write_all(frame.as_bytes())?;
+    stdout.flush()
+}
+
+/// Animates the carts in the terminal until `q` is pressed.
+pub fn play(input: &str) -> io::Result<()> {
+    let raw = RawMode::enter()?;
+    let keys = raw.keys()?;
+
+    let mut mine = parse_grid(input);
+    let mut map = Screen::for_mine(&mine);
+    draw_map(&mine, &mut map);
+    let mut screen = map.clone();
+
+    let mut ticks = 0;
+    let mut paused = false;
+    let mut step = DEFAULT_STEP;
+    let mut next_tick = Instant::now() + step;
+    loop {
+        screen.clone_from(&map);
+        draw_carts(&mine, &mut screen);
+        let status = format!(
+            "tick {}  carts {}  crashes {}  step {}ms{}",
+            ticks,
+            mine.carts.len(),
+            mine.crashes.len(),
+            step.as_secs() * 1000 + u64::from(step.subsec_millis()),
+            if paused { "  [paused]" } else { "" },
+        );
+        draw_frame(&screen, &status)?;
+
+        let now = Instant::now();
+        let key = if paused {
+            match keys.recv() {
+                Ok(key) => key,
+                Err(_) => break,
+            }
+        } else {
+            let wait = if next_tick > now {
+                next_tick - now
+            } else {
+                Duration::from_millis(0)
+            };
+            match keys.recv_timeout(wait) {
+                Ok(key) => key,
+                Err(RecvTimeoutError::Timeout) => {
+                    tick(&mut mine)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+                    ticks += 1;
+                    next_tick += step;
+                    continue;
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        };
+
+        match key.to_ascii_lowercase() {
+            b'q' => break,
+            b'r' => {
+                mine = parse_grid(input);
+                ticks = 0;
+            }
+            b'w' => step = faster(step),
+            b's' => step = slower(step),
+            b'z' => step = DEFAULT_STEP,
+            b' ' | b'p' => paused = !paused,
+            b'n' | b'.' => {
+                paused = true;
+                tick(&mut mine)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+                ticks += 1;
+            }
+            _ => {}
+        }
+        next_tick = Instant::now() + step;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,499 @@
+//! Runs every day against the puzzle's example input and against our own input,
+//! checking the answers we already know are right.
+
+use aoc2018::days::*;
+use aoc2018::solution::Solution;
+
+fn check(solution: &dyn Solution, input: &str, part1: &str, part2: &str) {
+    assert_eq!(
+        solution.part1(input).unwrap(),
+        part1,
+        "day {} part 1",
+        solution.day()
+    );
+    assert_eq!(
+        solution.part2(input).unwrap(),
+        part2,
+        "day {} part 2",
+        solution.day()
+    );
+}
+
+fn check_real(solution: &dyn Solution, part1: &str, part2: &str) {
+    check(solution, solution.input(), part1, part2);
+}
+
+#[test]
+fn day01() {
+    let example = include_str!("../src/days/day01e.txt");
+    check(&day01::Day01, example, "3", "2");
+    assert_eq!(day01::Day01.part2("+1\n-1\n").unwrap(), "0");
+    assert_eq!(day01::Day01.part2("+3\n+3\n+4\n-2\n-4\n").unwrap(), "10");
+    assert_eq!(day01::Day01.part2("-6\n+3\n+8\n+5\n-6\n").unwrap(), "5");
+    assert_eq!(day01::Day01.part2("+7\n+7\n-2\n-7\n-4\n").unwrap(), "14");
+    check_real(&day01::Day01, "538", "77271");
+}
+
+#[test]
+fn day02() {
+    let example = include_str!("../src/days/day02e.txt");
+    assert_eq!(day02::Day02.part1(example).unwrap(), "12");
+    let example = "abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz\n";
+    assert_eq!(day02::Day02.part2(example).unwrap(), "fgij");
+    check_real(&day02::Day02, "5368", "cvgywxqubnuaefmsljdrpfzyi");
+}
+
+#[test]
+fn day03() {
+    let example = include_str!("../src/days/day03e.txt");
+    check(&day03::Day03, example, "4", "3");
+    check_real(&day03::Day03, "105047", "658");
+}
+
+#[test]
+fn day04() {
+    let example = include_str!("../src/days/day04e.txt");
+    check(&day04::Day04, example, "240", "4455");
+    check_real(&day04::Day04, "12504", "139543");
+}
+
+#[test]
+fn day05() {
+    let example = include_str!("../src/days/day05e.txt");
+    check(&day05::Day05, example, "10", "4");
+    check_real(&day05::Day05, "10888", "6952");
+
+    // Reacting works on any stream of units, not just whole strings
+    let units = example.bytes().chain(b"Aa\n".iter().cloned());
+    assert_eq!(day05::react(units), b"dabCBAcaDA".to_vec());
+}
+
+#[test]
+fn day05_rules() {
+    use day05::{react_with, shortest_without_one_type, Reaction, ReactionRules};
+
+    let puzzle = ReactionRules::default();
+    assert_eq!(puzzle.reaction(b'c', b'C'), Reaction::Vanish);
+    assert_eq!(puzzle.unit_type(b'c'), puzzle.unit_type(b'C'));
+    assert_eq!(shortest_without_one_type(b"dabAcCaCBAcCcaDA", &puzzle), 4);
+
+    // Only lower case followed by upper case reacts
+    let mut ordered = ReactionRules::new(true);
+    ordered.cancel(b'a', b'A').cancel(b'b', b'B');
+    assert!(ordered.is_order_sensitive());
+    assert_eq!(react_with(b"AabBbA".iter().cloned(), &ordered), b"AabA");
+
+    // Any bytes can react, and products react with what came before them
+    let mut rules = ReactionRules::new(false);
+    rules.transform(b'x', b'y', b'z').cancel(b'z', b'0');
+    assert_eq!(react_with(b"0xy".iter().cloned(), &rules), b"");
+    assert_eq!(react_with(b"yx10".iter().cloned(), &rules), b"z10");
+    assert_eq!(shortest_without_one_type(b"xy0", &rules), 1);
+
+    // Taking out x frees a up to react with A instead of B
+    let mut rules = ReactionRules::new(false);
+    rules
+        .cancel(b'a', b'A')
+        .cancel(b'a', b'B')
+        .cancel(b'B', b'C');
+    assert_eq!(react_with(b"AaBC".iter().cloned(), &rules), b"");
+    assert_eq!(shortest_without_one_type(b"AxaBC", &rules), 0);
+}
+
+#[test]
+fn day06() {
+    let example = include_str!("../src/days/day06e.txt");
+    assert_eq!(day06::Day06.part1(example).unwrap(), "17");
+    check_real(&day06::Day06, "3006", "42998");
+}
+
+#[test]
+fn day07() {
+    let example = include_str!("../src/days/day07e.txt");
+    assert_eq!(day07::Day07.part1(example).unwrap(), "CABDFE");
+    let example = include_str!("../src/days/day07z.txt");
+    assert_eq!(day07::Day07.part1(example).unwrap(), "AB");
+    check_real(&day07::Day07, "CFGHAEMNBPRDISVWQUZJYTKLOX", "828");
+}
+
+#[test]
+fn day07_workers() {
+    use day07::{build_time, Durations};
+
+    let example = include_str!("../src/days/day07e.txt");
+    let letters = Durations::letters(0);
+    assert_eq!(letters.get("C"), Some(3));
+    assert_eq!(build_time(example, 2, |s| letters.get(s)).unwrap(), 15);
+    assert_eq!(build_time(example, 1, |_| Some(1)).unwrap(), 6);
+    assert_eq!(build_time(example, 3, |_| Some(0)).unwrap(), 0);
+    assert!(build_time(example, 0, |s| letters.get(s)).is_err());
+    assert!(build_time(example, 2, |_| None).is_err());
+}
+
+#[test]
+fn day07_named_steps() {
+    use day07::{build_time, dep_graph, Durations};
+
+    let input = "\
+Step fetch must be finished before step Build can begin.
+Step configure must be finished before step Build can begin.
+Step Build must be finished before step AB can begin.
+";
+    assert_eq!(day07::part1(input).unwrap(), "configure fetch Build AB");
+    let graph = dep_graph(input).unwrap();
+    assert_eq!(graph.len(), 4);
+
+    let durations: Durations = "fetch takes 5 seconds\n\
+                                configure takes 2 seconds\n\
+                                Build takes 10 seconds\n\
+                                AB takes 1 seconds\n"
+        .parse()
+        .unwrap();
+    assert_eq!(build_time(input, 2, |s| durations.get(s)).unwrap(), 16);
+    assert_eq!(build_time(input, 1, |s| durations.get(s)).unwrap(), 18);
+
+    assert!(dep_graph("Step A must be done before B.\n").is_err());
+}
+
+#[test]
+fn day07_timeline() {
+    use day07::{timeline, Durations, Task};
+
+    let example = include_str!("../src/days/day07e.txt");
+    let letters = Durations::letters(0);
+    let timeline = timeline(example, 2, |s| letters.get(s)).unwrap();
+    assert_eq!(timeline.total, 15);
+    let f = Task {
+        worker: 1,
+        step: "F".to_string(),
+        start: 3,
+        end: 9,
+    };
+    assert!(timeline.tasks.contains(&f));
+
+    let table = "\
+Second   Worker 1   Worker 2   Done
+   0        C          .
+   1        C          .
+   2        C          .
+   3        A          F       C
+   4        B          F       CA
+   5        B          F       CA
+   6        D          F       CAB
+   7        D          F       CAB
+   8        D          F       CAB
+   9        D          .       CABF
+  10        E          .       CABFD
+  11        E          .       CABFD
+  12        E          .       CABFD
+  13        E          .       CABFD
+  14        E          .       CABFD
+  15        .          .       CABFDE
+";
+    assert_eq!(timeline.table(), table);
+
+    let svg = timeline.to_svg();
+    assert!(svg.starts_with("<svg"));
+    assert_eq!(svg.matches("<rect").count(), 6);
+}
+
+#[test]
+fn day08() {
+    let example = include_str!("../src/days/day08e.txt");
+    check(&day08::Day08, example, "138", "66");
+    check_real(&day08::Day08, "41760", "25737");
+}
+
+#[test]
+fn day08_deep_trees() {
+    use day08::{parse_tree, Visit};
+
+    // A chain of nodes far deeper than the stack could cope with recursively
+    let depth = 200_000;
+    let mut input = "1 1 ".repeat(depth - 1);
+    input += "0 2 5 6";
+    input += &" 1".repeat(depth - 1);
+
+    let tree = parse_tree(&input).unwrap();
+    assert_eq!(tree.meta_sum(), 11 + depth as i32 - 1);
+    assert_eq!(tree.value(), 11);
+    assert_eq!(tree.depth(), depth);
+    assert_eq!(tree.leaf_count(), 1);
+    assert_eq!(tree.max_metadata(), Some(6));
+    assert_eq!(tree.to_flat(), input);
+
+    let mut copy = tree.clone();
+    assert_eq!(copy, tree);
+    let mut leaf = &mut copy;
+    while !leaf.children.is_empty() {
+        leaf = &mut leaf.children[0];
+    }
+    leaf.metadata[1] = 7;
+    assert_ne!(copy, tree);
+    let debug = format!("{:?}", tree);
+    assert!(debug.starts_with("Node { children: [Node { children: ["));
+    assert!(debug.ends_with("metadata: [1] }], metadata: [1] }"));
+
+    let example = parse_tree(include_str!("../src/days/day08e.txt")).unwrap();
+    assert_eq!(
+        format!("{:?}", example.children[1]),
+        "Node { children: [Node { children: [], metadata: [99] }], metadata: [2] }"
+    );
+    assert_eq!(example.depth(), 3);
+    assert_eq!(example.leaf_count(), 2);
+    assert_eq!(example.max_metadata(), Some(99));
+    let entered = example
+        .walk()
+        .filter(|v| match v {
+            Visit::Enter(_) => true,
+            Visit::Exit(_) => false,
+        })
+        .count();
+    assert_eq!(entered, 4);
+}
+
+#[test]
+fn day08_parse_and_export() {
+    use day08::{parse_tree, TreeError};
+
+    let example = include_str!("../src/days/day08e.txt");
+    let tree = parse_tree(example).unwrap();
+    assert_eq!(tree.to_flat(), example.trim());
+    assert_eq!(parse_tree(&tree.to_flat()).unwrap(), tree);
+
+    let leaf = parse_tree("0 2 7 8").unwrap();
+    assert_eq!(leaf.to_json(), r#"{"children":[],"metadata":[7,8]}"#);
+    let json = parse_tree("1 1 0 1 99 2").unwrap().to_json();
+    assert_eq!(
+        json,
+        r#"{"children":[{"children":[],"metadata":[99]}],"metadata":[2]}"#
+    );
+
+    let dot = parse_tree("2 1 0 1 5 0 1 6 1").unwrap().to_dot();
+    assert_eq!(
+        dot,
+        "digraph {\n    n0 [label=\"1\"];\n    n1 [label=\"5\"];\n    n0 -> n1;\n    \
+         n2 [label=\"6\"];\n    n0 -> n2;\n}\n"
+    );
+
+    let error = |token, message: &str| {
+        Err(TreeError {
+            token,
+            message: message.into(),
+        })
+    };
+    assert_eq!(
+        parse_tree("1 1 0 1 99"),
+        error(5, "expected a metadata entry, but the input ended")
+    );
+    assert_eq!(
+        parse_tree("0 1 5 6 7"),
+        error(3, "unexpected numbers after the end of the tree")
+    );
+    assert_eq!(
+        parse_tree("1 x"),
+        error(1, "expected a metadata count, got 'x'")
+    );
+    assert_eq!(
+        parse_tree("-1 0"),
+        error(0, "expected a child count, got '-1'")
+    );
+}
+
+#[test]
+fn day09() {
+    let example = include_str!("../src/days/day09e.txt");
+    assert_eq!(day09::Day09.part1(example).unwrap(), "32");
+
+    let examples = [
+        ("10 players; last marble is worth 1618 points", "8317"),
+        ("13 players; last marble is worth 7999 points", "146373"),
+        ("17 players; last marble is worth 1104 points", "2764"),
+        ("21 players; last marble is worth 6111 points", "54718"),
+        ("30 players; last marble is worth 5807 points", "37305"),
+    ];
+    for &(input, score) in examples.iter() {
+        assert_eq!(day09::Day09.part1(input).unwrap(), score, "{}", input);
+    }
+
+    check_real(&day09::Day09, "398242", "3273842452");
+}
+
+#[test]
+fn day09_rules() {
+    use day09::{game, GameRules, Turn};
+
+    let rules: GameRules = include_str!("../src/days/day09e.txt").parse().unwrap();
+    assert_eq!(rules, GameRules::new(9, 25));
+
+    let (scores, turns) = game(&rules);
+    assert_eq!(scores, [0, 0, 0, 0, 32, 0, 0, 0, 0]);
+    assert_eq!(turns.len(), 25);
+    assert_eq!(turns.iter().filter(|t| t.score > 0).count(), 1);
+    let scoring = Turn {
+        player: 4,
+        marble: 23,
+        score: 32,
+    };
+    assert_eq!(turns[22], scoring);
+
+    // Scoring on every 5th marble and taking the one 2 back
+    let variant = GameRules {
+        scoring_modulus: 5,
+        removal_offset: 2,
+        ..GameRules::new(2, 10)
+    };
+    let (scores, _) = game(&variant);
+    assert_eq!(scores, [5 + 3, 10 + 8]);
+
+    let lines = day09::trace(&rules, 23);
+    assert_eq!(lines.len(), 24);
+    assert_eq!(lines[0], "[-] (0)");
+    assert_eq!(lines[4], "[4] 0 (4) 2 1 3");
+    assert_eq!(
+        lines[23],
+        "[5] 0 16 8 17 4 18 (19) 2 20 10 21 5 22 11 1 12 6 13 3 14 7 15"
+    );
+    assert_eq!(day09::trace(&rules, 100).len(), 26);
+
+    assert!("0 players; last marble is worth 25 points"
+        .parse::<GameRules>()
+        .is_err());
+}
+
+#[test]
+fn day10() {
+    let example = include_str!("../src/days/day10e.txt");
+    let message = "\
++------------+
+| #   #  ### |
+| #   #   #  |
+| #   #   #  |
+| #####   #  |
+| #   #   #  |
+| #   #   #  |
+| #   #   #  |
+| #   #  ### |
++------------+";
+    check(&day10::Day10, example, message, "3");
+
+    // The same lights, a million seconds before they meet
+    let far_away: String = example
+        .lines()
+        .map(|line| {
+            let n: Vec<i32> = line
+                .split(['<', '>', ','])
+                .filter_map(|s| s.trim().parse().ok())
+                .collect();
+            let (x, y) = (n[0] - n[2] * 1_000_000, n[1] - n[3] * 1_000_000);
+            format!("position=<{},{}> velocity=<{},{}>\n", x, y, n[2], n[3])
+        })
+        .collect();
+    check(&day10::Day10, &far_away, message, "1000003");
+
+    check_real(&day10::Day10, "RLEZNRAN", "10240");
+}
+
+#[test]
+fn day11() {
+    let example = include_str!("../src/days/day11e.txt");
+    check(&day11::Day11, example, "33,45", "90,269,16");
+    check(&day11::Day11, "42", "21,61", "232,251,12");
+    check_real(&day11::Day11, "233,36", "231,107,14");
+}
+
+#[test]
+fn day11_best_square() {
+    use day11::{best_square, cell, power_level};
+
+    assert_eq!(power_level(8, cell(3, 5)), 4);
+    assert_eq!(power_level(57, cell(122, 79)), -5);
+    assert_eq!(best_square(18, 300, 3..=3), (cell(33, 45), 3, 29));
+    assert_eq!(best_square(42, 300, 3..=3), (cell(21, 61), 3, 30));
+    assert_eq!(best_square(18, 300, 1..=300), (cell(90, 269), 16, 113));
+    assert_eq!(best_square(42, 300, 10..=20), (cell(232, 251), 12, 119));
+
+    // Far corners of big grids and huge serials don't overflow
+    assert_eq!(power_level(18, cell(2000, 2000)), -4);
+    assert_eq!(power_level(i32::MAX, cell(300, 300)), 0);
+    assert_eq!(best_square(18, 2000, 3..=3).1, 3);
+
+    // The top left corner is 1,1 whatever size the grid is
+    assert_eq!(
+        best_square(18, 1, 1..=1),
+        (cell(1, 1), 1, power_level(18, cell(1, 1)))
+    );
+}
+
+#[test]
+fn day12() {
+    let example = include_str!("../src/days/day12e.txt");
+    assert_eq!(day12::Day12.part1(example).unwrap(), "325");
+    check_real(&day12::Day12, "2140", "1900000000384");
+}
+
+#[test]
+fn day13() {
+    let example = r"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/
+";
+    assert_eq!(day13::Day13.part1(example).unwrap(), "7,3");
+
+    let example = r"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/
+";
+    assert_eq!(day13::Day13.part2(example).unwrap(), "6,4");
+
+    let example = include_str!("../src/days/day13e.txt");
+    check(&day13::Day13, example, "7,18", "12,15");
+
+    check_real(&day13::Day13, "5,102", "46,45");
+}
+
+#[test]
+fn bad_input_is_an_error() {
+    assert!(day01::part1("+1\n+x\n").is_err());
+    assert!(day01::part2("+1\n").is_err());
+    assert!(day01::part2("+2\n-1\n+2\n").is_err());
+    let err = day04::part1("[1518-11-01 00:75] falls asleep\n").unwrap_err();
+    assert!(err.to_string().starts_with("line 1, column 16: minute 75"));
+    let early_wake = "[1518-11-01 00:00] Guard #10 begins shift\n\
+                      [1518-11-01 00:05] wakes up\n";
+    assert!(day04::part1(early_wake).is_err());
+    let backwards = "[1518-11-01 23:50] Guard #10 begins shift\n\
+                     [1518-11-01 23:59] falls asleep\n\
+                     [1518-11-02 00:05] wakes up\n";
+    assert!(day04::part1(backwards).is_err());
+    let same_hour = "[1518-11-01 00:00] Guard #10 begins shift\n\
+                     [1518-11-01 00:30] falls asleep\n\
+                     [1518-11-01 00:30] wakes up\n";
+    assert!(day04::part1(same_hour).is_err());
+    let cycle = "Step A must be finished before step B can begin.\n\
+                 Step B must be finished before step A can begin.\n";
+    assert!(day07::part1(cycle).is_err());
+    assert!(day07::part2(cycle).is_err());
+    assert!(day09::part1("no players here").is_err());
+    let huge = format!("1 players; last marble is worth {} points", usize::MAX);
+    assert!(day09::part1(&huge).is_err());
+    assert!(day09::part2("10 players; last marble is worth 4294967295 points").is_err());
+    assert!(day10::part2("").is_err());
+    assert!(day11::part1("not a serial").is_err());
+    assert!(day12::part1("garbage\n\n...## => #\n").is_err());
+    let err = day12::part1("initial state: #..x#\n\n...## => #\n").unwrap_err();
+    assert!(err.to_string().starts_with("line 1, column 19: "));
+    assert!(day13::part1("-->-\n").is_err());
+    assert!(day13::part1(">  <\n").is_err());
+    assert!(day13::part2(">\n").is_err());
+    let chasing = "/>->\\\n|   |\n\\---/\n";
+    assert!(day13::part1(chasing).is_err());
+    assert!(day13::part2(chasing).is_err());
+}
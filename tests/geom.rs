@@ -0,0 +1,59 @@
+use aoc2018::geom::{Direction, Direction::*, Point2i, Recti, Vector2i};
+
+#[test]
+fn rects_include_both_corners() {
+    let r = [(2, 1), (4, 3), (3, 2)]
+        .iter()
+        .fold(Recti::EMPTY, |r, &p| r.combined_with(p));
+    assert_eq!(r, Recti::new(2, 1, 4, 3));
+    assert_eq!(r.area(), 9);
+    assert_eq!(r.points().count(), 9);
+    assert_eq!(r.points().next(), Some(Point2i::new(2, 1)));
+
+    assert!(r.is_on_edge(Point2i::new(4, 2)));
+    assert!(!r.is_on_edge(Point2i::new(3, 2)));
+    assert!(!r.is_on_edge(Point2i::new(5, 2)));
+
+    assert_eq!(Recti::EMPTY.area(), 0);
+    assert_eq!(Recti::EMPTY.points().count(), 0);
+}
+
+#[test]
+fn points_and_vectors() {
+    let p = Point2i::new(1, 6);
+    let q = Point2i::new(8, 3);
+    assert_eq!(p.manhattan(q), 10);
+    assert_eq!(p + (q - p), q);
+    assert_eq!(p + Vector2i::new(2, -1) * 3, Point2i::new(7, 3));
+}
+
+#[test]
+fn neighbours() {
+    let p = Point2i::new(0, 0);
+    let n4: Vec<_> = p.neighbours4().collect();
+    assert_eq!(
+        n4,
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .iter()
+            .map(|&c| Point2i::from(c))
+            .collect::<Vec<_>>()
+    );
+
+    let n8: Vec<_> = p.neighbours8().collect();
+    assert_eq!(n8.len(), 8);
+    assert!(n8
+        .iter()
+        .all(|n| n != &p && (n.x - p.x).abs() <= 1 && (n.y - p.y).abs() <= 1));
+    assert!(n4.iter().all(|n| n8.contains(n)));
+}
+
+#[test]
+fn rotation() {
+    for &dir in Direction::ALL.iter() {
+        assert_eq!(dir.turn_left().turn_right(), dir);
+        assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+        assert_eq!(dir.vector() + dir.reverse().vector(), Vector2i::ZERO);
+    }
+    assert_eq!(North.turn_right(), East);
+    assert_eq!(North.vector(), Vector2i::new(0, -1));
+}
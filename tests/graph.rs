@@ -0,0 +1,81 @@
+use aoc2018::graph::{Cycle, DepGraph, NodeId};
+
+fn graph(edges: &[(&str, &str)]) -> DepGraph {
+    let mut graph = DepGraph::new();
+    for &(before, after) in edges {
+        graph.add_edge(before, after);
+    }
+    graph
+}
+
+fn names(graph: &DepGraph, ids: &[NodeId]) -> Vec<String> {
+    ids.iter().map(|&id| graph.name(id).to_string()).collect()
+}
+
+#[test]
+fn topo_sort_picks_the_first_name() {
+    let g = graph(&[
+        ("C", "A"),
+        ("C", "F"),
+        ("A", "B"),
+        ("A", "D"),
+        ("B", "E"),
+        ("D", "E"),
+        ("F", "E"),
+    ]);
+    assert_eq!(
+        names(&g, &g.topo_sort().unwrap()),
+        ["C", "A", "B", "D", "F", "E"]
+    );
+
+    let mut g = graph(&[
+        ("fetch", "build"),
+        ("build", "test"),
+        ("configure", "build"),
+    ]);
+    g.add_node("lint");
+    let order = names(&g, &g.topo_sort().unwrap());
+    assert_eq!(order, ["configure", "fetch", "build", "lint", "test"]);
+    assert_eq!(g.find_cycle(), None);
+}
+
+#[test]
+fn cycles_are_reported() {
+    let g = graph(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "b"), ("x", "a")]);
+    let cycle = g.topo_sort().unwrap_err();
+    assert_eq!(cycle.steps, ["b", "c", "d"]);
+    assert_eq!(
+        cycle.to_string(),
+        "the steps form a cycle: b -> c -> d -> b"
+    );
+
+    let g = graph(&[("me", "me")]);
+    let me = Cycle {
+        steps: vec!["me".to_string()],
+    };
+    assert_eq!(g.find_cycle(), Some(me));
+}
+
+#[test]
+fn critical_path_follows_the_slowest_chain() {
+    let g = graph(&[("a", "b"), ("a", "c"), ("b", "d"), ("c", "d")]);
+    let cost = |id| match g.name(id) {
+        "c" => 10,
+        _ => 1,
+    };
+    let (length, path) = g.critical_path(cost).unwrap();
+    assert_eq!(length, 12);
+    assert_eq!(names(&g, &path), ["a", "c", "d"]);
+
+    assert_eq!(DepGraph::new().critical_path(cost).unwrap(), (0, vec![]));
+}
+
+#[test]
+fn dot_export() {
+    let mut g = graph(&[("a", "say \"hi\"")]);
+    g.add_node("alone");
+    assert_eq!(
+        g.to_dot(),
+        "digraph {\n    \"a\";\n    \"say \\\"hi\\\"\";\n    \"alone\";\n    \"a\" -> \"say \\\"hi\\\"\";\n}\n"
+    );
+}
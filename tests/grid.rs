@@ -0,0 +1,50 @@
+use aoc2018::geom::Point2i;
+use aoc2018::grid::Grid;
+
+fn p(x: i32, y: i32) -> Point2i {
+    Point2i::new(x, y)
+}
+
+#[test]
+fn from_map_pads_short_lines() {
+    let grid = Grid::from_map("#.#\n.\n##\n\n", |_, c| c);
+    assert_eq!((grid.width(), grid.height()), (3, 3));
+    assert_eq!(grid[p(2, 0)], '#');
+    assert_eq!(grid[p(2, 1)], ' ');
+    assert_eq!(grid.to_string(), "#.#\n.  \n## \n");
+}
+
+#[test]
+fn get_is_bounds_checked() {
+    let mut grid = Grid::new(2, 3, 0u8);
+    assert_eq!(grid.get(p(1, 2)), Some(&0));
+    assert_eq!(grid.get(p(2, 0)), None);
+    assert_eq!(grid.get(p(0, -1)), None);
+
+    *grid.get_mut(p(1, 2)).unwrap() = 7;
+    assert_eq!(grid[p(1, 2)], 7);
+    assert!(grid.get_mut(p(-1, 0)).is_none());
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds_panics() {
+    let grid = Grid::new(2, 2, 0u8);
+    let _ = grid[p(2, 2)];
+}
+
+#[test]
+fn rows_columns_and_neighbours() {
+    let grid = Grid::from_fn(3, 2, |pt| pt.y * 3 + pt.x);
+    assert_eq!(grid.row(1), &[3, 4, 5]);
+    assert_eq!(grid.rows().count(), 2);
+    assert_eq!(grid.column(1).cloned().collect::<Vec<_>>(), [1, 4]);
+    assert_eq!(grid.iter().nth(4), Some((p(1, 1), &4)));
+
+    let n4: Vec<_> = grid.neighbours4(p(0, 0)).map(|(_, &v)| v).collect();
+    assert_eq!(n4, [1, 3]);
+    assert_eq!(grid.neighbours8(p(1, 0)).count(), 5);
+
+    let doubled = grid.map(|v| v * 2);
+    assert_eq!(doubled[p(2, 1)], 10);
+}
@@ -0,0 +1,66 @@
+use aoc2018::geom::Point2i;
+use aoc2018::ocr::read_message;
+
+/// The `#`s in some ASCII art, shifted so its top left corner is at `origin`.
+fn points(art: &[&str], origin: Point2i) -> Vec<Point2i> {
+    let mut points = vec![];
+    for (line, y) in art.iter().zip(0..) {
+        for (c, x) in line.chars().zip(0..) {
+            if c == '#' {
+                points.push(Point2i::new(origin.x + x, origin.y + y));
+            }
+        }
+    }
+    points
+}
+
+#[rustfmt::skip]
+const GHJK: [&str; 10] = [
+    " ####   #    #     ###  #    #",
+    "#    #  #    #      #   #   #",
+    "#       #    #      #   #  #",
+    "#       #    #      #   # #",
+    "#       ######      #   ##",
+    "#  ###  #    #      #   ##",
+    "#    #  #    #      #   # #",
+    "#    #  #    #  #   #   #  #",
+    "#   ##  #    #  #   #   #   #",
+    " ### #  #    #   ###    #    #",
+];
+
+#[test]
+fn reads_letters_anywhere() {
+    assert_eq!(
+        read_message(points(&GHJK, Point2i::ORIGIN)),
+        Some("GHJK".to_string())
+    );
+    assert_eq!(
+        read_message(points(&GHJK, Point2i::new(-140, 93))),
+        Some("GHJK".to_string())
+    );
+
+    let h = points(&GHJK, Point2i::ORIGIN)
+        .into_iter()
+        .filter(|p| p.x >= 8 && p.x < 14)
+        .collect::<Vec<_>>();
+    assert_eq!(read_message(h), Some("H".to_string()));
+}
+
+#[test]
+fn rejects_things_that_arent_letters() {
+    assert_eq!(read_message(vec![]), None);
+
+    // A stray light in the gap between two letters
+    let mut stray = points(&GHJK, Point2i::ORIGIN);
+    stray.push(Point2i::new(7, 4));
+    assert_eq!(read_message(stray), None);
+
+    // Letters that are too short, like the ones in the day 10 example
+    let short = points(&GHJK[2..], Point2i::ORIGIN);
+    assert_eq!(read_message(short), None);
+
+    // A shape the right size that isn't in the font
+    let mut smudged = points(&GHJK, Point2i::ORIGIN);
+    smudged.push(Point2i::new(2, 2));
+    assert_eq!(read_message(smudged), None);
+}
@@ -0,0 +1,65 @@
+use aoc2018::parse::{parse_lines, scan_fields, LineError, ParseError};
+use aoc2018::scan;
+
+fn scan_error(pattern: &str, line: &str, names: &[&str]) -> LineError {
+    scan_fields(pattern, line, names).unwrap_err()
+}
+
+#[test]
+fn fields_know_their_columns() {
+    let fields = scan_fields("#{} @ {},{}", "#12 @  3,4", &["id", "x", "y"]).unwrap();
+    let found: Vec<_> = fields.iter().map(|f| (f.text, f.column)).collect();
+    assert_eq!(found, [("12", 2), ("3", 8), ("4", 10)]);
+}
+
+#[test]
+fn scan_errors_point_at_the_problem() {
+    assert_eq!(
+        scan_error("#{} @ {}", "#12 @ ", &["id", "x"]),
+        LineError::new(7, "missing `x`")
+    );
+    assert_eq!(
+        scan_error("#{} @ {},{}", "#12 @ 3;4", &["id", "x", "y"]),
+        LineError::new(7, "expected ',' after `x`")
+    );
+    assert_eq!(
+        scan_error("#{} @ {}", "12 @ 3", &["id", "x"]),
+        LineError::new(1, "expected '#'")
+    );
+    assert_eq!(
+        scan_error("{}x{}!", "3x4! and more", &["w", "h"]),
+        LineError::new(5, "unexpected trailing text")
+    );
+
+    let bad_value = scan!("{}x{}", w, h)("3xfour").map(|(w, h): (i32, i32)| w * h);
+    assert_eq!(
+        bad_value,
+        Err(LineError::new(3, "invalid value for `h`: 'four'"))
+    );
+}
+
+#[test]
+fn parse_errors_have_line_and_caret() {
+    let input = "1x2\n\n3x4\n5xsix\n";
+    let err: ParseError = parse_lines(input, |line| {
+        scan!("{}x{}", w, h)(line).map(|(w, h): (i32, i32)| w * h)
+    })
+    .unwrap_err();
+
+    // Blank lines still count towards the line number
+    assert_eq!((err.line, err.column), (4, 3));
+    assert_eq!(err.text, "5xsix");
+    assert_eq!(
+        err.to_string(),
+        "line 4, column 3: invalid value for `h`: 'six'\n    5xsix\n      ^"
+    );
+
+    let err = parse_lines("10,20,x\n", |line| {
+        scan!("{},{},{}", a, b, c)(line).map(|t: (i32, i32, i32)| t.0)
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 7: invalid value for `c`: 'x'\n    10,20,x\n          ^"
+    );
+}
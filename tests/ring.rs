@@ -0,0 +1,60 @@
+use aoc2018::ring::Ring;
+
+fn contents(ring: &Ring<u32>) -> Vec<u32> {
+    ring.iter().cloned().collect()
+}
+
+#[test]
+fn insert_and_rotate() {
+    let mut ring = Ring::new();
+    assert_eq!(ring.current(), None);
+    ring.rotate(3);
+
+    for i in 0..5 {
+        ring.insert_after(i);
+    }
+    assert_eq!(ring.len(), 5);
+    assert_eq!(contents(&ring), [4, 0, 1, 2, 3]);
+
+    ring.rotate(2);
+    assert_eq!(ring.current(), Some(&1));
+    ring.rotate(-3);
+    assert_eq!(ring.current(), Some(&3));
+    ring.rotate(-12);
+    assert_eq!(ring.current(), Some(&1));
+    ring.rotate(5_000_001);
+    assert_eq!(ring.current(), Some(&2));
+    assert_eq!(contents(&ring), [2, 3, 4, 0, 1]);
+}
+
+#[test]
+fn remove_moves_clockwise() {
+    let mut ring = Ring::new();
+    for i in 0..6 {
+        ring.insert_after(i);
+    }
+
+    ring.rotate(-1);
+    assert_eq!(ring.remove_current(), Some(4));
+    assert_eq!(contents(&ring), [5, 0, 1, 2, 3]);
+
+    // Removing from the front of the storage moves the last node into its slot
+    ring.rotate(1);
+    assert_eq!(ring.remove_current(), Some(0));
+    assert_eq!(contents(&ring), [1, 2, 3, 5]);
+
+    *ring.current_mut().unwrap() = 10;
+    ring.insert_after(11);
+    assert_eq!(contents(&ring), [11, 2, 3, 5, 10]);
+
+    while ring.len() > 1 {
+        ring.remove_current();
+    }
+    assert_eq!(contents(&ring), [10]);
+    assert_eq!(ring.remove_current(), Some(10));
+    assert!(ring.is_empty());
+    assert_eq!(ring.remove_current(), None);
+
+    ring.insert_after(7);
+    assert_eq!(contents(&ring), [7]);
+}
@@ -0,0 +1,40 @@
+use aoc2018::geom::Recti;
+use aoc2018::grid::Grid;
+use aoc2018::summed_area::SummedAreaTable;
+
+fn brute_sum(grid: &Grid<i32>, rect: Recti) -> i32 {
+    rect.points().map(|p| grid[p]).sum()
+}
+
+#[test]
+fn rect_sum_matches_adding_up_cells() {
+    let grid = Grid::from_fn(7, 4, |p| p.x * 3 - p.y * 5 + 1);
+    let table = SummedAreaTable::from_grid(&grid);
+    assert_eq!((table.width(), table.height()), (7, 4));
+
+    for top in 0..4 {
+        for bottom in top..4 {
+            for left in 0..7 {
+                for right in left..7 {
+                    let rect = Recti::new(left, top, right, bottom);
+                    assert_eq!(table.rect_sum(rect), brute_sum(&grid, rect), "{:?}", rect);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn empty_rects_sum_to_zero() {
+    let table = SummedAreaTable::from_fn(3, 3, |_| 1u32);
+    assert_eq!(table.rect_sum(Recti::new(2, 0, 1, 2)), 0);
+    assert_eq!(table.rect_sum(Recti::EMPTY), 0);
+    assert_eq!(table.rect_sum(Recti::new(0, 0, 2, 2)), 9);
+}
+
+#[test]
+#[should_panic]
+fn rect_outside_the_table_panics() {
+    let table = SummedAreaTable::from_fn(3, 2, |_| 1);
+    table.rect_sum(Recti::new(1, 0, 3, 1));
+}
@@ -0,0 +1,86 @@
+//! Renders day 13 frames with the software backend and checks a few pixels.
+
+use aoc2018::days::day13::parse_grid;
+use aoc2018::days::day13::tick;
+use aoc2018::viz::software::Canvas;
+use aoc2018::viz::terminal::Screen;
+use aoc2018::viz::{self, BGCOLOR, CART_COLOR, TRACK_COLOR};
+
+const SCALE: usize = 10;
+
+/// The pixel at the middle of a grid cell, allowing for the half cell margin.
+fn cell_center(canvas: &Canvas, x: usize, y: usize) -> u32 {
+    canvas.pixel((x + 1) * SCALE, (y + 1) * SCALE)
+}
+
+#[test]
+fn software_frame() {
+    let mine = parse_grid(include_str!("../src/days/day13e.txt"));
+
+    let mut canvas = Canvas::for_mine(&mine, SCALE);
+    viz::draw_map(&mine, &mut canvas);
+    viz::draw_carts(&mine, &mut canvas);
+
+    let cart = mine.carts[0].pos;
+    assert_eq!(
+        cell_center(&canvas, cart.x as usize, cart.y as usize),
+        CART_COLOR
+    );
+    assert_eq!(cell_center(&canvas, 0, 0), BGCOLOR);
+
+    let mut ppm = Vec::new();
+    canvas.write_ppm(&mut ppm).unwrap();
+    let header = format!("P6\n{} {}\n255\n", canvas.width(), canvas.height());
+    assert!(ppm.starts_with(header.as_bytes()));
+    assert_eq!(
+        ppm.len(),
+        header.len() + canvas.width() * canvas.height() * 3
+    );
+}
+
+#[test]
+fn software_tracks() {
+    let mine = parse_grid("/->-\\\n|   |\n\\---/\n");
+
+    let mut canvas = Canvas::for_mine(&mine, SCALE);
+    viz::draw_map(&mine, &mut canvas);
+
+    assert_eq!(cell_center(&canvas, 1, 0), TRACK_COLOR);
+    assert_eq!(cell_center(&canvas, 0, 1), TRACK_COLOR);
+    assert_eq!(cell_center(&canvas, 2, 2), TRACK_COLOR);
+    assert_eq!(cell_center(&canvas, 1, 1), BGCOLOR);
+}
+
+#[test]
+fn terminal_frame() {
+    let example = r"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/
+";
+    let mut mine = parse_grid(example);
+    for _ in 0..2 {
+        tick(&mut mine).unwrap();
+    }
+
+    let mut screen = Screen::for_mine(&mine);
+    viz::draw_map(&mine, &mut screen);
+    viz::draw_carts(&mine, &mut screen);
+
+    let expected = [
+        r"/--->",
+        r"|   |  /----\",
+        r"| /-+--+-\  |",
+        r"| | |  | |  |",
+        r"\-+-/  \-+>-/",
+        r"  \------/",
+    ];
+    let text = screen.render(false);
+    assert_eq!(
+        text.lines().map(str::trim_end).collect::<Vec<_>>(),
+        expected
+    );
+    assert!(screen.render(true).contains("\x1b[1;35m>\x1b[0m"));
+}